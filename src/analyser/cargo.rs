@@ -1,4 +1,4 @@
-use crate::core::types;
+use crate::{analyser::linker, core::types};
 
 /// Contains the analyser code for the [`crate::config::ParserKind::Cargo`]
 #[must_use]
@@ -10,6 +10,8 @@ pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
 
     for i in 0..*line_len {
         if let Some(line) = lines.get(i) {
+            errors.extend(linker::parse(line, &lines[i + 1..], project_dir));
+
             if line.starts_with("error: ")
                 || line.starts_with("error[")
                 || line.starts_with("warning: ")
@@ -340,4 +342,23 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn should_find_linker_error() {
+        static LOG: &str = include_str!("../../tests/cargo_link_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "error: undefined reference to `missing_fn' in function `app::main'"
+                    .to_string(),
+                locations: vec![types::Location {
+                    path: "/tmp/project/src/main.rs".to_string(),
+                    row: 8,
+                    col: 0
+                }]
+            }]
+        );
+    }
 }
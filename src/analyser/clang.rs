@@ -1,24 +1,196 @@
-use crate::core::types;
+use std::path::Path;
+
+use crate::{
+    analyser::{linker, sourcemap},
+    core::types,
+};
 
 /// Contains the analyser code for the [`crate::config::ParserKind::Clang`]
 #[must_use]
 pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
     let mut errors: Vec<types::Message> = vec![];
+    // Relative paths are relative to the directory make or ninja is working in
+    let mut directories: Vec<String> = vec![];
     let lines = log.lines().collect::<Vec<&str>>();
     let lines = lines.as_slice();
     let line_len = &lines.len();
 
     for i in 0..*line_len {
         if let Some(line) = lines.get(i) {
-            if let Some(error) = parse_line(line, project_dir) {
+            if let Some(directory) = parse_entering(line, project_dir) {
+                directories.push(directory);
+                continue;
+            }
+            if is_leaving(line) {
+                directories.pop();
+                continue;
+            }
+            if is_make(line) {
+                continue;
+            }
+            if let Some(error) = parse_cmake(line, &lines[i + 1..], project_dir) {
                 errors.push(error);
+                continue;
+            }
+            let directory = directories.last().map_or(project_dir, String::as_str);
+            let mut found = linker::parse(line, &lines[i + 1..], directory);
+            // Lines of the linker like "/usr/bin/ld: main.o: in function `main':" are no diagnostics
+            if !linker::is_linker(line) {
+                found.extend(parse_line(line, directory));
+            }
+            if !directories.is_empty() {
+                for location in found.iter_mut().flat_map(|error| &mut error.locations) {
+                    location.path = sourcemap::normalise(Path::new(&location.path));
+                }
             }
+            errors.extend(found);
         }
     }
 
     errors
 }
 
+/// Parses the directory make, ninja or cmake is working in
+///
+/// Gets input like:
+/// "`make[2]: Entering directory '/tmp/project/lib'`"
+///                               ----------------
+///                               directory
+///
+/// Or "``ninja: Entering directory `build'``" and "`-- Build files have been written to: /tmp/project/build`"
+fn parse_entering(line: &str, project_dir: &str) -> Option<String> {
+    let directory = line
+        .strip_prefix("-- Build files have been written to: ")
+        .or_else(|| {
+            line.split_once(": Entering directory ")
+                .map(|(_, rest)| rest)
+        })?;
+    let directory = directory.trim().trim_matches(['\'', '`', '"']);
+    if directory.is_empty() {
+        return None;
+    }
+
+    if directory.starts_with('/') {
+        Some(directory.to_string())
+    } else {
+        Some(sourcemap::normalise(
+            &Path::new(project_dir).join(directory),
+        ))
+    }
+}
+
+fn is_leaving(line: &str) -> bool {
+    line.split_once(": Leaving directory ").is_some()
+}
+
+/// Checks for lines of make itself like "`make[1]: *** [Makefile:8: parser.o] Error 1`"
+fn is_make(line: &str) -> bool {
+    line.starts_with("make: ") || line.starts_with("make[") || line.starts_with("ninja: ")
+}
+
+/// Parses a configuration error of cmake
+///
+/// Gets input like:
+/// "`CMake Error at src/CMakeLists.txt:12 (add_executable):`"
+///        -----    ------------------ --  --------------
+///        severity path               row command
+///
+/// Followed by the indented message
+fn parse_cmake(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let (kind, rest) = line.strip_prefix("CMake ")?.split_once(" at ")?;
+    let severity = if kind == "Error" {
+        "error"
+    } else if kind.contains("Warning") {
+        "warning"
+    } else {
+        return None;
+    };
+    let (location, mut command) = parse_cmake_frame(rest.strip_suffix(':')?, project_dir)?;
+    let mut locations = vec![];
+
+    // Errors of modules like "/usr/share/cmake-3.28/Modules/FindZLIB.cmake" start in the project
+    if !Path::new(&location.path).starts_with(project_dir) {
+        if let Some((frame, frame_command)) = parse_cmake_call_stack(lines, project_dir) {
+            locations.push(frame);
+            command = frame_command;
+        }
+    }
+    locations.push(location);
+
+    Some(types::Message {
+        error: format!("{severity}: {} [{command}]", parse_cmake_message(lines)),
+        locations,
+    })
+}
+
+/// Parses a location of cmake
+///
+/// Gets input like:
+/// "`src/CMakeLists.txt:12 (add_executable)`"
+///   ------------------ --  --------------
+///   path               row command
+fn parse_cmake_frame<'a>(frame: &'a str, project_dir: &str) -> Option<(types::Location, &'a str)> {
+    let (location, command) = frame.trim().strip_suffix(')')?.split_once(" (")?;
+    let (path, row) = location.rsplit_once(':')?;
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    let location = types::Location {
+        path,
+        row: row.parse().ok()?,
+        col: 0,
+    };
+    Some((location, command))
+}
+
+/// Finds the first frame of the project in the call stack of a cmake message
+///
+/// Gets input like:
+/// "`Call Stack (most recent call first):`"
+/// "`  /usr/share/cmake-3.28/Modules/FindZLIB.cmake:199 (FIND_PACKAGE_HANDLE_STANDARD_ARGS)`"
+/// "`  CMakeLists.txt:8 (find_package)`"
+fn parse_cmake_call_stack<'a>(
+    lines: &[&'a str],
+    project_dir: &str,
+) -> Option<(types::Location, &'a str)> {
+    let start = lines
+        .iter()
+        .take_while(|line| !line.starts_with("CMake ") && !line.starts_with("-- "))
+        .position(|line| line.starts_with("Call Stack "))?;
+
+    lines[start + 1..]
+        .iter()
+        .take_while(|line| line.starts_with(' '))
+        .filter_map(|line| parse_cmake_frame(line, project_dir))
+        .find(|(location, _)| Path::new(&location.path).starts_with(project_dir))
+}
+
+/// Joins the first paragraph of an indented cmake message
+///
+/// A paragraph ending with ":" like "Cannot find source file:" is continued by the next one
+fn parse_cmake_message(lines: &[&str]) -> String {
+    let mut message: Vec<&str> = vec![];
+
+    for line in lines {
+        if !line.starts_with(' ') && !line.is_empty() {
+            break;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            if message.last().is_some_and(|last| !last.ends_with(':')) {
+                break;
+            }
+            continue;
+        }
+        message.push(line);
+    }
+
+    message.join(" ")
+}
+
 /// Parse message from line with delimiter
 ///
 /// Gets line input like:
@@ -45,7 +217,7 @@ fn parse_line(line: &str, project_dir: &str) -> Option<types::Message> {
 ///  path                |  |
 ///                      |  col
 ///                      row
-fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
+pub fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
     let mut spl = location.splitn(4, ':');
     let mut path = spl.next()?.to_string();
     // To full path if not already the case
@@ -125,4 +297,114 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn should_follow_make_directories() {
+        static LOG: &str = include_str!("../../tests/make_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: implicit declaration of function ‘tokenize’ [-Wimplicit-function-declaration]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/parser.c".to_string(),
+                        row: 27,
+                        col: 9
+                    }]
+                },
+                types::Message {
+                    error: "warning: ‘capacity’ defined but not used [-Wunused-variable]"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/include/buffer.h".to_string(),
+                        row: 4,
+                        col: 12
+                    }]
+                },
+                types::Message {
+                    error: "warning: return type defaults to ‘int’ [-Wimplicit-int]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/main.c".to_string(),
+                        row: 5,
+                        col: 1
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_cmake_errors() {
+        static LOG: &str = include_str!("../../tests/cmake_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "warning: cmake_minimum_required() should be called prior to this top-level project() call. [project]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/CMakeLists.txt".to_string(),
+                        row: 3,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: Cannot find source file: src/missing.c [add_executable]"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/CMakeLists.txt".to_string(),
+                        row: 12,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_cmake_module_errors_in_the_project() {
+        static LOG: &str = include_str!("../../tests/cmake_3.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "error: Could NOT find ZLIB (missing: ZLIB_LIBRARY ZLIB_INCLUDE_DIR) [find_package]".to_string(),
+                locations: vec![
+                    types::Location {
+                        path: "/tmp/project/CMakeLists.txt".to_string(),
+                        row: 8,
+                        col: 0
+                    },
+                    types::Location {
+                        path: "/usr/share/cmake-3.28/Modules/FindPackageHandleStandardArgs.cmake"
+                            .to_string(),
+                        row: 230,
+                        col: 0
+                    }
+                ]
+            }]
+        );
+    }
+
+    #[test]
+    fn should_resolve_paths_of_the_build_directory() {
+        static LOG: &str = include_str!("../../tests/cmake_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "error: use of undeclared identifier 'count'".to_string(),
+                locations: vec![types::Location {
+                    path: "/tmp/project/src/main.c".to_string(),
+                    row: 9,
+                    col: 5
+                }]
+            }]
+        );
+    }
 }
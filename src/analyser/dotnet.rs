@@ -0,0 +1,215 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Dotnet`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_diagnostic(line, project_dir) {
+                // MSBuild repeats all diagnostics in the summary after "Build FAILED."
+                if !errors.contains(&error) {
+                    errors.push(error);
+                }
+            }
+            if let Some(error) = parse_failed_test(line, &lines[i + 1..], project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses a diagnostic of `MSBuild`
+///
+/// Gets input like:
+/// "`/tmp/project/Program.cs(12,34): error CS1002: ; expected [/tmp/project/Api.csproj]`"
+///   ------------------------ -- --  ------------  --------   ----------------------
+///   path                     |  col code          message    project
+///                            row
+///
+/// Diagnostics of tools like "`CSC : error CS5001: ...`" point to the project
+fn parse_diagnostic(line: &str, project_dir: &str) -> Option<types::Message> {
+    let (origin, rest) = line.trim().split_once(": ")?;
+    let (severity, rest) = rest.split_once(' ')?;
+    if severity != "error" && severity != "warning" {
+        return None;
+    }
+    let (code, message) = rest.split_once(": ")?;
+    let (message, project) = match message.strip_suffix(']') {
+        Some(message) => {
+            let (message, project) = message.rsplit_once(" [")?;
+            (message, Some(project))
+        }
+        None => (message, None),
+    };
+
+    let location = match origin.strip_suffix(')') {
+        Some(origin) => {
+            let (path, position) = origin.rsplit_once('(')?;
+            let mut position = position.split(',');
+            types::Location {
+                path: to_path(path, project_dir),
+                row: position.next()?.parse().ok()?,
+                col: position
+                    .next()
+                    .and_then(|col| col.parse().ok())
+                    .unwrap_or(0),
+            }
+        }
+        None => types::Location {
+            path: to_path(project?, project_dir),
+            row: 0,
+            col: 0,
+        },
+    };
+
+    Some(types::Message {
+        error: format!("{severity} {code}: {message}"),
+        locations: vec![location],
+    })
+}
+
+/// Parses a failed test of "dotnet test"
+///
+/// Gets input like:
+/// "`  Failed Api.Tests.CalculatorTests.Add_ReturnsSum [12 ms]`"
+///           --------------------------------------
+///           test
+///
+/// Followed by the "Error Message:" and the "Stack Trace:" of the test
+fn parse_failed_test(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let test = line
+        .trim()
+        .strip_prefix("Failed ")?
+        .split_whitespace()
+        .next()?;
+    if lines.first()?.trim() != "Error Message:" {
+        return None;
+    }
+    let message = lines.get(1)?.trim();
+
+    let locations = lines
+        .iter()
+        .skip_while(|line| line.trim() != "Stack Trace:")
+        .skip(1)
+        .take_while(|line| line.trim().starts_with("at "))
+        .filter_map(|line| parse_frame(line, project_dir))
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(types::Message {
+        error: format!("{test}: {message}"),
+        locations,
+    })
+}
+
+/// Parses a stack frame that has debug symbols
+///
+/// Gets input like:
+/// "`at Api.Tests.UserTests.Load_Throws() in /tmp/project/UserTests.cs:line 22`"
+///                                           -------------------------      --
+///                                           path                           row
+fn parse_frame(line: &str, project_dir: &str) -> Option<types::Location> {
+    let (_, location) = line.trim().strip_prefix("at ")?.rsplit_once(" in ")?;
+    let (path, row) = location.rsplit_once(":line ")?;
+
+    Some(types::Location {
+        path: to_path(path, project_dir),
+        row: row.parse().ok()?,
+        col: 0,
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::dotnet::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_build_errors() {
+        static LOG: &str = include_str!("../../tests/dotnet_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error CS1002: ; expected".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Api/Program.cs".to_string(),
+                        row: 12,
+                        col: 34
+                    }]
+                },
+                types::Message {
+                    error: "warning CS0168: The variable 'e' is declared but never used"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Api/Services/UserService.cs".to_string(),
+                        row: 20,
+                        col: 17
+                    }]
+                },
+                types::Message {
+                    error: "error CS5001: Program does not contain a static 'Main' method suitable for an entry point".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Worker/Worker.csproj".to_string(),
+                        row: 0,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_failed_tests() {
+        static LOG: &str = include_str!("../../tests/dotnet_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "Api.Tests.CalculatorTests.Add_ReturnsSum: Assert.Equal() Failure: Values differ".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/tests/Api.Tests/CalculatorTests.cs".to_string(),
+                        row: 14,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Api.Tests.UserTests.Load_Throws: System.InvalidOperationException : Sequence contains no elements".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/Api/Services/UserService.cs".to_string(),
+                            row: 31,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/tests/Api.Tests/UserTests.cs".to_string(),
+                            row: 22,
+                            col: 0
+                        }
+                    ]
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use std::{fs, path::Path};
+
+use crate::core::types;
+
+/// Elixir and Erlang applications whose frames are not part of the project
+const RUNTIME_APPS: [&str; 8] = [
+    "elixir", "ex_unit", "stdlib", "kernel", "logger", "mix", "iex", "eex",
+];
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Elixir`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+    let app = project_app(project_dir);
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_diagnostic(line, &lines[i + 1..], project_dir) {
+                errors.push(error);
+            }
+            if let Some(error) = parse_compile_error(line, project_dir) {
+                errors.push(error);
+            }
+            if let Some(error) =
+                parse_failed_test(line, &lines[i + 1..], project_dir, app.as_deref())
+            {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses a warning or error of the compiler
+///
+/// Gets input like:
+/// "`warning: variable "count" is unused`"
+///
+/// The location is in the following lines like "`└─ lib/app/cart.ex:12:5: App.Cart.total/1`"
+/// or "`lib/app/report.ex:30: App.Report (module)`" for Elixir before 1.15
+fn parse_diagnostic(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let error = line.trim();
+    if !error.starts_with("warning: ") && !error.starts_with("error: ") {
+        return None;
+    }
+
+    let locations = lines
+        .iter()
+        .take_while(|line| line.starts_with(char::is_whitespace))
+        .filter_map(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix("└─ ").unwrap_or(line);
+            parse_location(line, project_dir)
+        })
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(types::Message {
+        error: error.to_string(),
+        locations,
+    })
+}
+
+/// Parses an exception of the compiler
+///
+/// Gets input like:
+/// "`** (CompileError) lib/app/legacy.ex:5: undefined function helper/0`"
+///       ------------  -------------------  ----------------------
+///       exception     location             message
+fn parse_compile_error(line: &str, project_dir: &str) -> Option<types::Message> {
+    let (exception, rest) = line.strip_prefix("** (")?.split_once(") ")?;
+    let (location, message) = rest.split_once(": ")?;
+
+    Some(types::Message {
+        error: format!("{exception}: {message}"),
+        locations: vec![parse_location(location, project_dir)?],
+    })
+}
+
+/// Parses a failure of `ExUnit`
+///
+/// Gets input like:
+/// "`  1) test adds numbers (App.MathTest)`"
+///        ----------------------------------
+///        test
+///
+/// Followed by the location of the test, the message and the stacktrace.
+/// The frames of the stacktrace point to the failing assertion.
+fn parse_failed_test(
+    line: &str,
+    lines: &[&str],
+    project_dir: &str,
+    app: Option<&str>,
+) -> Option<types::Message> {
+    let (number, test) = line.trim().split_once(") ")?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let test_location = parse_location(lines.first()?.trim(), project_dir)?;
+
+    let body = lines[1..]
+        .iter()
+        .map(|line| line.trim())
+        .take_while(|line| !line.is_empty() && *line != "stacktrace:")
+        .collect::<Vec<_>>();
+    let message = body.first()?.trim_start_matches("** ");
+    let values = body
+        .iter()
+        .filter(|line| line.starts_with("left:") || line.starts_with("right:"))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>();
+    let message = if values.is_empty() {
+        message.to_string()
+    } else {
+        format!("{message} ({})", values.join(", "))
+    };
+
+    let mut locations = lines
+        .iter()
+        .skip_while(|line| line.trim() != "stacktrace:")
+        .skip(1)
+        .take_while(|line| !line.trim().is_empty())
+        .filter_map(|line| parse_frame(line, project_dir, app))
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        locations.push(test_location);
+    }
+
+    Some(types::Message {
+        error: format!("{test}: {message}"),
+        locations,
+    })
+}
+
+/// Parses a frame of a stacktrace
+///
+/// Gets input like:
+/// "`(app 0.1.0) lib/app/user.ex:8: App.User.load!/1`"
+///    ---        ----------------
+///    app        location
+///
+/// Frames of Elixir and Erlang itself are ignored.
+/// Frames of dependencies like "`(ecto 3.11.0) lib/ecto/repo.ex:12`" are ignored
+/// unless the file exists in the project.
+fn parse_frame(line: &str, project_dir: &str, app: Option<&str>) -> Option<types::Location> {
+    let line = line.trim();
+    let Some(rest) = line.strip_prefix('(') else {
+        return parse_location(line, project_dir);
+    };
+    let (dependency, rest) = rest.split_once(") ")?;
+    let dependency = dependency.split_whitespace().next()?;
+    if RUNTIME_APPS.contains(&dependency) {
+        return None;
+    }
+
+    let location = parse_location(rest, project_dir)?;
+
+    (app == Some(dependency) || Path::new(&location.path).exists()).then_some(location)
+}
+
+/// Reads the name of the project app from the "`mix.exs`" like "`app: :shop,`"
+fn project_app(project_dir: &str) -> Option<String> {
+    let mix = fs::read_to_string(Path::new(project_dir).join("mix.exs")).ok()?;
+    let (_, rest) = mix.split_once("app: :")?;
+    let app = rest
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .next()?;
+
+    Some(app.to_string())
+}
+
+/// Parses location
+///
+/// Gets location input like:
+/// "`lib/app/cart.ex:12:5: App.Cart.total/1`" or "`test/app/math_test.exs:5`"
+///   --------------- -- -                          ---------------------- -
+///   path            |  col                        path                   row
+///                   row
+fn parse_location(line: &str, project_dir: &str) -> Option<types::Location> {
+    let location = line.split(": ").next()?;
+    let mut splits = location.splitn(3, ':');
+    let path = splits.next()?;
+    let row = splits.next()?.parse().ok()?;
+    let col = splits.next().and_then(|col| col.parse().ok()).unwrap_or(0);
+
+    let is_source = [".ex", ".exs", ".eex", ".heex"]
+        .iter()
+        .any(|extension| path.ends_with(extension));
+    if !is_source || path.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    Some(types::Location { path, row, col })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::elixir::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_compile_errors() {
+        static LOG: &str = include_str!("../../tests/elixir_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "warning: variable \"count\" is unused (if the variable is not meant to be used, prefix it with an underscore)".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/app/cart.ex".to_string(),
+                        row: 12,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "warning: function format/1 is unused".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/app/report.ex".to_string(),
+                        row: 30,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: undefined variable \"prices\"".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/app/cart.ex".to_string(),
+                        row: 8,
+                        col: 14
+                    }]
+                },
+                types::Message {
+                    error: "CompileError: undefined function helper/0 (expected App.Legacy to define such a function or for it to be imported, but none are available)".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/app/legacy.ex".to_string(),
+                        row: 5,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_failed_tests() {
+        static LOG: &str = include_str!("../../tests/elixir_2.log");
+        let project_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/elixir_project");
+        let result = analyse(LOG, project_dir);
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "test adds numbers (App.MathTest): Assertion with == failed (left: 3, right: 4)".to_string(),
+                    locations: vec![types::Location {
+                        path: format!("{project_dir}/test/app/math_test.exs"),
+                        row: 6,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "test loads the user (App.UserTest): (RuntimeError) user not found"
+                        .to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: format!("{project_dir}/lib/app/user.ex"),
+                            row: 8,
+                            col: 0
+                        },
+                        types::Location {
+                            path: format!("{project_dir}/test/app/user_test.exs"),
+                            row: 11,
+                            col: 0
+                        }
+                    ]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_ignore_frames_of_dependencies() {
+        static LOG: &str = include_str!("../../tests/elixir_3.log");
+        let project_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/elixir_project");
+        let result = analyse(LOG, project_dir);
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "test saves the order (App.OrderTest): (Ecto.InvalidChangesetError) could not perform insert because changeset is invalid.".to_string(),
+                locations: vec![
+                    types::Location {
+                        path: format!("{project_dir}/lib/app/order.ex"),
+                        row: 21,
+                        col: 0
+                    },
+                    types::Location {
+                        path: format!("{project_dir}/test/app/order_test.exs"),
+                        row: 16,
+                        col: 0
+                    }
+                ]
+            }]
+        );
+    }
+}
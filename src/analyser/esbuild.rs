@@ -0,0 +1,272 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Esbuild`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_block(line, &lines[i + 1..], project_dir) {
+                errors.push(error);
+            }
+            if let Some(error) = parse_line(line, project_dir) {
+                errors.push(error);
+            }
+            let previous = i.checked_sub(1).and_then(|i| lines.get(i)).copied();
+            if let Some(error) = parse_rollup_line(line, previous, project_dir) {
+                errors.push(error);
+            }
+            if let Some(error) = parse_server_error(line, &lines[i + 1..], project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses the header of an esbuild message and the locations below it
+///
+/// Gets input like:
+/// "`✘ [ERROR] Could not resolve "left-pad"`"
+///    -------  ------------------------
+///    severity message
+///
+/// The first location is where the error is, the following are from notes.
+/// The block ends at the first line that is not indented, blank lines separate the notes.
+fn parse_block(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let (severity, message) = parse_header(line)?;
+
+    let locations = lines
+        .iter()
+        .take_while(|line| line.is_empty() || line.starts_with(char::is_whitespace))
+        .filter_map(|line| parse_location(line.trim().strip_suffix(':')?, project_dir))
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(types::Message {
+        error: format!("{severity}: {message}"),
+        locations,
+    })
+}
+
+fn parse_header(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let line = ["✘ ", "X ", "▲ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))?;
+
+    if let Some(message) = line.strip_prefix("[ERROR] ") {
+        return Some(("error", message));
+    }
+    line.strip_prefix("[WARNING] ")
+        .map(|message| ("warning", message))
+}
+
+/// Parses the compact format that vite prints for esbuild errors
+///
+/// Gets input like:
+/// "`/tmp/project/src/main.ts:8:14: ERROR: Unexpected "}"`"
+///   ----------------------------  -----  --------------
+///   location                      |      message
+///                                 severity
+fn parse_line(line: &str, project_dir: &str) -> Option<types::Message> {
+    let (location, rest) = line.split_once(": ")?;
+    let (severity, message) = if let Some(message) = rest.strip_prefix("ERROR: ") {
+        ("error", message)
+    } else {
+        ("warning", rest.strip_prefix("WARNING: ")?)
+    };
+
+    Some(types::Message {
+        error: format!("{severity}: {message}"),
+        locations: vec![parse_location(location, project_dir)?],
+    })
+}
+
+/// Parses a build error of rollup that is used by vite
+///
+/// Gets input like:
+/// "`src/App.vue (4:9): "helper" is not exported by "src/lib.ts"`"
+///   -----------  - -   ----------------------------------------
+///   path         | col message
+///                row
+///
+/// The line has to follow "`error during build:`" or start with a prefix like "`[vite]: `"
+/// because other output can look the same.
+fn parse_rollup_line(
+    line: &str,
+    previous: Option<&str>,
+    project_dir: &str,
+) -> Option<types::Message> {
+    let prefixed = ["[vite]: ", "[rollup]: ", "error: ", "RollupError: "]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix));
+    let line = match prefixed {
+        Some(line) => line,
+        None if previous.is_some_and(|previous| previous.trim() == "error during build:") => line,
+        None => return None,
+    };
+    let (path, rest) = line.split_once(" (")?;
+    let (position, message) = rest.split_once("): ")?;
+    let (row, col) = position.split_once(':')?;
+    if path.is_empty() || path.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some(types::Message {
+        error: format!("error: {message}"),
+        locations: vec![types::Location {
+            path: to_path(path, project_dir),
+            row: row.parse().ok()?,
+            col: col.parse().ok()?,
+        }],
+    })
+}
+
+/// Parses an error of the vite dev server
+///
+/// Gets input like:
+/// "`12:01:33 [vite] Internal server error: Failed to resolve import "./missing"`"
+///                                          ----------------------------------
+///                                          message
+///
+/// The location is in the following "`File: /tmp/project/src/router.ts:2:21`" line
+fn parse_server_error(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let (_, message) = line.split_once("Internal server error: ")?;
+    let location = lines
+        .iter()
+        .take_while(|line| line.starts_with(char::is_whitespace))
+        .find_map(|line| line.trim().strip_prefix("File: "))?;
+
+    Some(types::Message {
+        error: format!("error: {message}"),
+        locations: vec![parse_location(location, project_dir)?],
+    })
+}
+
+/// Parses location
+///
+/// Gets location input like:
+/// "`src/main.ts:3:7`"
+///   ----------- - -
+///   path        | col
+///               row
+fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
+    let mut splits = location.rsplitn(3, ':');
+    let col = splits.next()?.parse().ok()?;
+    let row = splits.next()?.parse().ok()?;
+    let path = splits.next()?;
+    if path.is_empty() || path.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some(types::Location {
+        path: to_path(path, project_dir),
+        row,
+        col,
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::esbuild::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_esbuild_errors() {
+        static LOG: &str = include_str!("../../tests/esbuild_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: Could not resolve \"left-pad\"".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main.ts".to_string(),
+                        row: 3,
+                        col: 7
+                    }]
+                },
+                types::Message {
+                    error: "warning: Duplicate key \"a\" in object literal [duplicate-object-key]"
+                        .to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/util.ts".to_string(),
+                            row: 1,
+                            col: 17
+                        },
+                        types::Location {
+                            path: "/tmp/project/src/util.ts".to_string(),
+                            row: 1,
+                            col: 12
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "error: Expected \";\" but found \"world\"".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/other.ts".to_string(),
+                        row: 2,
+                        col: 6
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_vite_errors() {
+        static LOG: &str = include_str!("../../tests/esbuild_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: Unexpected \"}\"".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main.ts".to_string(),
+                        row: 8,
+                        col: 14
+                    }]
+                },
+                types::Message {
+                    error: "error: \"helper\" is not exported by \"src/lib.ts\", imported by \"src/App.vue\"."
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/App.vue".to_string(),
+                        row: 4,
+                        col: 9
+                    }]
+                },
+                types::Message {
+                    error: "error: Failed to resolve import \"./missing\" from \"src/router.ts\". Does the file exist?"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/router.ts".to_string(),
+                        row: 2,
+                        col: 21
+                    }]
+                }
+            ]
+        );
+    }
+}
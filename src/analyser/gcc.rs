@@ -39,4 +39,47 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn linker_error() {
+        static LOG: &str = include_str!("../../tests/linker_1.log");
+        let result = analyse(LOG, "/tmp/project");
+        assert_eq!(
+            result,
+            vec![
+                Message {
+                    error: "error: undefined reference to `tokenize' in function `parse'".to_string(),
+                    locations: vec![Location {
+                        path: "/tmp/project/src/parser.c".to_string(),
+                        row: 27,
+                        col: 0,
+                    }],
+                },
+                Message {
+                    error: "error: undefined reference to `tokenize' in function `parse'".to_string(),
+                    locations: vec![Location {
+                        path: "/tmp/project/src/parser.c".to_string(),
+                        row: 31,
+                        col: 0,
+                    }],
+                },
+                Message {
+                    error: "error: undefined reference to `render' in function `main'".to_string(),
+                    locations: vec![Location {
+                        path: "/tmp/project/main.c".to_string(),
+                        row: 0,
+                        col: 0,
+                    }],
+                },
+                Message {
+                    error: "error: multiple definition of `init'; /tmp/ccQ2.o:/tmp/project/src/config.c:3: first defined here in function `init'".to_string(),
+                    locations: vec![Location {
+                        path: "/tmp/project/src/app.c".to_string(),
+                        row: 9,
+                        col: 0,
+                    }],
+                },
+            ]
+        );
+    }
 }
@@ -0,0 +1,192 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Ghc`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_diagnostic(line, &lines[i + 1..], project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses a diagnostic and its indented body
+///
+/// Gets input like:
+/// "`src/Foo.hs:12:5-17: error: [GHC-88464]`"
+///   ----------------   -----  -----------
+///   location           |      codes
+///                      severity
+///
+/// The message is the first paragraph or bullet of the body
+fn parse_diagnostic(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let (location, rest) = line.split_once(": ")?;
+    let (severity, rest) = rest.split_once(':')?;
+    if severity != "error" && severity != "warning" {
+        return None;
+    }
+    let location = parse_location(location, project_dir)?;
+
+    // The codes look like "[GHC-66111] [-Wunused-imports]", the message can contain "[a]"
+    let rest = rest.trim();
+    let mut inline = rest;
+    while let Some((code, after)) = inline
+        .strip_prefix('[')
+        .and_then(|group| group.split_once(']'))
+    {
+        if !code.starts_with("GHC-") && !code.starts_with('-') {
+            break;
+        }
+        inline = after.trim_start();
+    }
+    let codes = rest[..rest.len() - inline.len()].trim_end();
+
+    let mut message = vec![];
+    if !inline.is_empty() {
+        message.push(inline);
+    }
+    for line in lines {
+        let trimmed = line.trim();
+        if !line.starts_with(char::is_whitespace) || is_code_frame(trimmed) {
+            break;
+        }
+        if trimmed.starts_with('•') && !message.is_empty() {
+            break;
+        }
+        message.push(trimmed.trim_start_matches('•').trim_start());
+    }
+    if message.is_empty() {
+        return None;
+    }
+
+    let message = message.join(" ");
+    let error = if codes.is_empty() {
+        format!("{severity}: {message}")
+    } else {
+        format!("{severity}: {message} {codes}")
+    };
+
+    Some(types::Message {
+        error,
+        locations: vec![location],
+    })
+}
+
+/// Checks for the source lines like "`12 |     foo 3`" below a body
+fn is_code_frame(line: &str) -> bool {
+    line.trim_start_matches(|c: char| c.is_ascii_digit())
+        .trim_start()
+        .starts_with('|')
+}
+
+/// Parses location
+///
+/// Gets location input like:
+/// "`src/Foo.hs:12:5-17`" or "`src/Foo.hs:(20,9)-(21,30)`"
+///   ---------- -- -          ----------  -- -
+///   path       |  col        path        |  col
+///              row                       row
+fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
+    if let Some((path, range)) = location.split_once(":(") {
+        let (row, rest) = range.split_once(',')?;
+        let (col, _) = rest.split_once(')')?;
+        return Some(types::Location {
+            path: to_path(path, project_dir),
+            row: row.parse().ok()?,
+            col: col.parse().ok()?,
+        });
+    }
+
+    let mut splits = location.rsplitn(3, ':');
+    let col = splits.next()?;
+    let col = col.split('-').next()?;
+    let row = splits.next()?;
+    let path = splits.next()?;
+    if path.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some(types::Location {
+        path: to_path(path, project_dir),
+        row: row.parse().ok()?,
+        col: col.parse().ok()?,
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::ghc::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_errors() {
+        static LOG: &str = include_str!("../../tests/ghc_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "warning: The import of ‘Data.List’ is redundant except perhaps to import instances from ‘Data.List’ To import instances alone, use: import Data.List() [GHC-66111] [-Wunused-imports]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/App/Util.hs".to_string(),
+                        row: 3,
+                        col: 1
+                    }]
+                },
+                types::Message {
+                    error: "error: Variable not in scope: handleRequest :: Int -> IO () [GHC-88464]"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/App/Server.hs".to_string(),
+                        row: 12,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "error: Couldn't match expected type ‘Int’ with actual type ‘[Char]’ [GHC-83865]"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/App/Server.hs".to_string(),
+                        row: 20,
+                        col: 9
+                    }]
+                },
+                types::Message {
+                    error: "error: parse error on input ‘where’".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/Main.hs".to_string(),
+                        row: 7,
+                        col: 1
+                    }]
+                },
+                types::Message {
+                    error: "error: Couldn't match type ‘[a]’ with ‘Maybe a’ [GHC-83865]"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/App/Config.hs".to_string(),
+                        row: 9,
+                        col: 14
+                    }]
+                }
+            ]
+        );
+    }
+}
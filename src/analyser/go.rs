@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use crate::core::types;
 
 /// Contains the analyser code for the [`crate::config::ParserKind::Go`]
@@ -16,13 +18,24 @@ pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
             if let Some(err) = parse_failed_test(line, lines.get(i + 1), project_dir) {
                 errors.push(err);
             }
+            if let Some(err) = parse_panic(line, &lines[i + 1..], project_dir) {
+                errors.push(err);
+            }
         }
     }
 
     errors
 }
 
+/// Parses an error of the compiler, go vet or a linter
+///
+/// Gets input like:
+/// "`main.go:14:2: this value of err is never used (staticcheck)`"
+///   ------- -- -  ------------------------------  -----------
+///   path    |  col message                         linter
+///           row
 fn parse_line_error(line: &str, project_dir: &str) -> Option<types::Message> {
+    let line = line.strip_prefix("vet: ").unwrap_or(line);
     let mut splits = line.splitn(4, ':');
     let mut file = splits.next()?;
     if file.starts_with("./") {
@@ -38,12 +51,28 @@ fn parse_line_error(line: &str, project_dir: &str) -> Option<types::Message> {
         col: col.parse().unwrap_or_default(),
     };
 
+    let error = match parse_linter(message) {
+        Some((message, linter)) => format!("{message} [{linter}]"),
+        None => message.to_string(),
+    };
+
     Some(types::Message {
-        error: message.to_string(),
+        error,
         locations: vec![location],
     })
 }
 
+/// Splits the linter name that golangci-lint and staticcheck append to a message
+fn parse_linter(message: &str) -> Option<(&str, &str)> {
+    let (message, linter) = message.strip_suffix(')')?.rsplit_once(" (")?;
+    let is_name = !linter.is_empty()
+        && linter
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    is_name.then_some((message, linter))
+}
+
 fn parse_failed_test(line: &str, next: Option<&&str>, project_dir: &str) -> Option<types::Message> {
     if !line.starts_with("--- FAIL: ") {
         // no failed test
@@ -52,6 +81,10 @@ fn parse_failed_test(line: &str, next: Option<&&str>, project_dir: &str) -> Opti
 
     let next = next?;
     let next = next.trim();
+    // A panic in the test is reported with its stack by `parse_panic`
+    if next.starts_with("panic: ") {
+        return None;
+    }
     let mut splits = next.splitn(3, ':');
 
     let file = splits.next()?;
@@ -70,9 +103,62 @@ fn parse_failed_test(line: &str, next: Option<&&str>, project_dir: &str) -> Opti
     })
 }
 
+/// Parses a panic and the stack of the goroutine that panicked
+///
+/// Gets input like:
+/// "`panic: runtime error: index out of range [5] with length 3`"
+///
+/// Only frames of files inside the `project_dir` are used
+fn parse_panic(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    if !line.starts_with("panic: ") {
+        return None;
+    }
+    let message = line.strip_suffix(" [recovered]").unwrap_or(line);
+
+    let locations = lines
+        .iter()
+        .skip_while(|line| !line.starts_with("goroutine "))
+        .skip(1)
+        .take_while(|line| !line.trim().is_empty())
+        .filter_map(|line| parse_frame(line, project_dir))
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(types::Message {
+        error: message.to_string(),
+        locations,
+    })
+}
+
+/// Parses the location of a goroutine frame
+///
+/// Gets input like:
+/// "`\t/tmp/project/main.go:12 +0x1d`"
+///     -------------------- --
+///     path                 row
+fn parse_frame(line: &str, project_dir: &str) -> Option<types::Location> {
+    let location = line.strip_prefix('\t')?.split_whitespace().next()?;
+    let (path, row) = location.rsplit_once(':')?;
+    // "/tmp/project2" is not part of "/tmp/project"
+    if !Path::new(path).starts_with(project_dir) {
+        return None;
+    }
+
+    Some(types::Location {
+        path: path.to_string(),
+        row: row.parse().ok()?,
+        col: 0,
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{analyser::go::analyse, core::types};
+    use crate::{
+        analyser::go::{analyse, parse_frame},
+        core::types,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -111,6 +197,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_find_lint_errors() {
+        static LOG: &str = include_str!("../../tests/go_lint.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "Error return value of `f.Close` is not checked [errcheck]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/internal/store/store.go".to_string(),
+                        row: 23,
+                        col: 12
+                    }]
+                },
+                types::Message {
+                    error: "SA4006: this value of `err` is never used [staticcheck]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/main.go".to_string(),
+                        row: 14,
+                        col: 2
+                    }]
+                },
+                types::Message {
+                    error: "func `unused` is unused [unused]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/main.go".to_string(),
+                        row: 30,
+                        col: 6
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_vet_errors() {
+        static LOG: &str = include_str!("../../tests/go_vet.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "fmt.Printf format %d has arg name of wrong type string".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/cmd/server.go".to_string(),
+                        row: 18,
+                        col: 3
+                    }]
+                },
+                types::Message {
+                    error: "unreachable code".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/main.go".to_string(),
+                        row: 9,
+                        col: 2
+                    }]
+                },
+                types::Message {
+                    error: "should omit type int from declaration of var x; it will be inferred from the right-hand side [ST1023]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/util.go".to_string(),
+                        row: 4,
+                        col: 1
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_panic() {
+        static LOG: &str = include_str!("../../tests/go_panic.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "panic: runtime error: index out of range [5] with length 3".to_string(),
+                locations: vec![
+                    types::Location {
+                        path: "/tmp/project/internal/store/store.go".to_string(),
+                        row: 42,
+                        col: 0
+                    },
+                    types::Location {
+                        path: "/tmp/project/internal/store/store_test.go".to_string(),
+                        row: 12,
+                        col: 0
+                    }
+                ]
+            }]
+        );
+    }
+
     #[test]
     fn should_find_failed_test() {
         static LOG: &str = include_str!("../../tests/go_test.log");
@@ -128,4 +311,20 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn should_ignore_frames_of_sibling_directories() {
+        assert_eq!(
+            parse_frame("\t/tmp/project/main.go:12 +0x1d", "/tmp/project"),
+            Some(types::Location {
+                path: "/tmp/project/main.go".to_string(),
+                row: 12,
+                col: 0
+            })
+        );
+        assert_eq!(
+            parse_frame("\t/tmp/project2/main.go:12 +0x1d", "/tmp/project"),
+            None
+        );
+    }
 }
@@ -1,9 +1,15 @@
-use crate::core::types;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{analyser::junit, core::types};
 
 /// Contains the analyser code for the [`crate::config::ParserKind::Gradle`]
 #[must_use]
 pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
     let mut errors: Vec<types::Message> = vec![];
+    let mut console_tests: Vec<(Option<String>, &str, types::Message)> = vec![];
 
     let lines = log.lines().collect::<Vec<&str>>();
     let lines = lines.as_slice();
@@ -14,16 +20,39 @@ pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
         if let Some(line) = lines.get(i) {
             let line = line.trim();
 
+            // "> Task :services:api:test" is the task "test" of the module "services/api"
             if line.starts_with("> Task :") {
                 let line = line.trim_start_matches("> Task :");
-                if let Some((module, _)) = line.split_once(':') {
-                    current_module = Some(module);
+                if let Some((module, _)) = line.rsplit_once(':') {
+                    current_module = Some(module.replace(':', "/"));
                 }
             }
 
-            // Only works with "./gradlew test --info"
+            // The stack trace is only printed with "./gradlew test --info"
             if line.ends_with("FAILED") {
-                parse_failed_test(i, line_len, lines, project_dir, current_module, &mut errors);
+                parse_failed_test(
+                    i,
+                    line_len,
+                    lines,
+                    project_dir,
+                    current_module.as_deref(),
+                    &mut errors,
+                );
+            }
+
+            if let Some((test, error)) = parse_console_test(
+                line,
+                lines.get(i + 1),
+                project_dir,
+                current_module.as_deref(),
+            ) {
+                // With "--parallel" a failed test can be printed again in the summary
+                let is_known = console_tests
+                    .iter()
+                    .any(|(module, known, _)| *module == current_module && *known == test);
+                if !is_known {
+                    console_tests.push((current_module.clone(), test, error));
+                }
             }
 
             if line.starts_with(project_dir) {
@@ -34,6 +63,30 @@ pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
         }
     }
 
+    // The JUnit reports of a module have the message and the full class name
+    // With "--parallel" the output of a module can be interleaved with others
+    let mut modules: Vec<Option<&str>> = vec![];
+    for (module, _, _) in &console_tests {
+        if !modules.contains(&module.as_deref()) {
+            modules.push(module.as_deref());
+        }
+    }
+    for module in modules {
+        let reported = report_failures(project_dir, module);
+        // Reports of earlier runs or of tasks that did not run are ignored
+        errors.extend(
+            console_tests
+                .iter()
+                .filter(|(test_module, _, _)| test_module.as_deref() == module)
+                .map(|(_, test, error)| {
+                    reported
+                        .iter()
+                        .find(|(reported_test, _)| reported_test == test)
+                        .map_or_else(|| error.clone(), |(_, error)| error.clone())
+                }),
+        );
+    }
+
     errors
 }
 
@@ -74,22 +127,12 @@ fn parse_failed_test(
                     if let Some((filename, line)) = rest.split_once(&format!(".{language}:")) {
                         let line_number = line.trim_end_matches(')');
 
-                        let language_identifier = match language {
-                            "java" => "java",
-                            "kt" => "kotlin",
-                            a => a,
-                        };
                         if let Some((class_path, _)) = path.split_once(filename) {
-                            let module =
-                                current_module.map_or(String::new(), |module| format!("/{module}"));
-                            let path = format!(
-                                "{}{}/src/test/{}/{}{}.{}",
+                            let path = test_source_path(
                                 project_dir,
-                                module,
-                                language_identifier,
-                                class_path.replace('.', "/"),
-                                filename,
-                                language
+                                current_module,
+                                &format!("{class_path}{filename}"),
+                                language,
                             );
                             if let Some(error) = msg {
                                 errors.push(types::Message {
@@ -109,6 +152,178 @@ fn parse_failed_test(
     }
 }
 
+/// Returns the path of a test class like "`org.example.AppTest`"
+fn test_source_path(
+    project_dir: &str,
+    module: Option<&str>,
+    class: &str,
+    language: &str,
+) -> String {
+    let module = module.map_or(String::new(), |module| format!("/{module}"));
+    let language_identifier = match language {
+        "java" => "java",
+        "kt" => "kotlin",
+        a => a,
+    };
+
+    format!(
+        "{project_dir}{module}/src/test/{language_identifier}/{}.{language}",
+        class.replace('.', "/")
+    )
+}
+
+/// Parses a failed test of the default console output
+///
+/// Gets input like:
+/// "`AppTest > appHasAGreeting() FAILED`"
+///   ----------------------------
+///   test
+///
+/// Followed by "`    org.opentest4j.AssertionFailedError at AppTest.java:13`".
+/// The package is not printed so the file is searched in the tests of the module.
+fn parse_console_test<'a>(
+    line: &'a str,
+    next: Option<&&str>,
+    project_dir: &str,
+    module: Option<&str>,
+) -> Option<(&'a str, types::Message)> {
+    let test = line.strip_suffix(" FAILED")?;
+    if line.starts_with("> Task") || !test.contains(" > ") {
+        return None;
+    }
+    let (exception, location) = next?.trim().rsplit_once(" at ")?;
+    let (file, row) = location.rsplit_once(':')?;
+    let (_, language) = file.rsplit_once('.')?;
+    if file.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let module_dir = module_dir(project_dir, module);
+    let path = find_file(&module_dir.join("src/test"), file).map_or_else(
+        || {
+            test_source_path(
+                project_dir,
+                module,
+                file.trim_end_matches(&format!(".{language}")),
+                language,
+            )
+        },
+        |path| path.to_string_lossy().to_string(),
+    );
+
+    Some((
+        test,
+        types::Message {
+            error: format!("{test}: {exception}"),
+            locations: vec![types::Location {
+                path,
+                row: row.parse().ok()?,
+                col: 0,
+            }],
+        },
+    ))
+}
+
+fn module_dir(project_dir: &str, module: Option<&str>) -> PathBuf {
+    let project_dir = Path::new(project_dir);
+    module.map_or_else(
+        || project_dir.to_path_buf(),
+        |module| project_dir.join(module),
+    )
+}
+
+/// Searches a file by name in a directory and its subdirectories
+fn find_file(dir: &Path, name: &str) -> Option<PathBuf> {
+    let mut entries = fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter_map(|entry| Some((entry.path(), entry.file_type().ok()?)))
+        .collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    // The file type does not follow symlinks, so a link to a parent can not loop
+    entries.iter().find_map(|(path, file_type)| {
+        if file_type.is_dir() {
+            find_file(path, name)
+        } else {
+            (path.file_name()? == name).then(|| path.clone())
+        }
+    })
+}
+
+/// Reads the failed tests of the "`build/test-results/**/TEST-*.xml`" reports of a module
+///
+/// Every failure is named like the console output "`AppTest > appHasAGreeting()`"
+fn report_failures(project_dir: &str, module: Option<&str>) -> Vec<(String, types::Message)> {
+    let module_dir = module_dir(project_dir, module);
+    let mut reports = vec![];
+    find_reports(&module_dir.join("build/test-results"), &mut reports);
+    reports.sort();
+
+    reports
+        .iter()
+        .filter_map(|report| fs::read_to_string(report).ok())
+        .flat_map(|xml| junit::failures(&xml))
+        .filter_map(|failure| {
+            let (row, language) = parse_report_frame(&failure.details, &failure.classname)?;
+            let simple_name = failure.classname.rsplit('.').next().unwrap_or_default();
+            let test = format!("{simple_name} > {}", failure.name);
+            Some((
+                test.clone(),
+                types::Message {
+                    error: format!("{test}: {}", failure.message),
+                    locations: vec![types::Location {
+                        path: test_source_path(project_dir, module, &failure.classname, language),
+                        row,
+                        col: 0,
+                    }],
+                },
+            ))
+        })
+        .collect()
+}
+
+fn find_reports(dir: &Path, reports: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            find_reports(&path, reports);
+        } else if name.starts_with("TEST-")
+            && path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"))
+        {
+            reports.push(path);
+        }
+    }
+}
+
+/// Finds the frame of the test class in the stack trace of a report
+///
+/// Gets input like:
+/// "`at app//org.example.AppTest.appHasAGreeting(AppTest.java:13)`"
+///                                                        ---- --
+///                                                        |    row
+///                                                        language
+fn parse_report_frame<'a>(details: &'a str, classname: &str) -> Option<(usize, &'a str)> {
+    details.lines().find_map(|line| {
+        let frame = line.trim().strip_prefix("at ")?;
+        let frame = frame.rsplit_once('/').map_or(frame, |(_, frame)| frame);
+        if !frame.starts_with(&format!("{classname}.")) {
+            return None;
+        }
+        let (_, location) = frame.rsplit_once('(')?;
+        let (file, row) = location.strip_suffix(')')?.rsplit_once(':')?;
+        let (_, language) = file.rsplit_once('.')?;
+
+        Some((row.parse().ok()?, language))
+    })
+}
+
 fn parse_error(line: &str, col_line: Option<&str>) -> Option<types::Message> {
     let mut split = line.split(':');
 
@@ -192,6 +407,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_find_test_error_without_info() {
+        static LOG: &str = include_str!("../../tests/gradle_test_default.log");
+        let project_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/gradle_project");
+        let result = analyse(LOG, project_dir);
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "AppTest > appHasAGreeting(): org.opentest4j.AssertionFailedError: expected: not <null>".to_string(),
+                    locations: vec![types::Location {
+                        path: format!("{project_dir}/app/src/test/java/org/example/AppTest.java"),
+                        row: 13,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "LibTest > sums(): org.opentest4j.AssertionFailedError".to_string(),
+                    locations: vec![types::Location {
+                        path: format!("{project_dir}/lib/src/test/kotlin/org/example/LibTest.kt"),
+                        row: 9,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_read_reports_once_for_interleaved_modules() {
+        static LOG: &str = include_str!("../../tests/gradle_test_parallel.log");
+        let project_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/gradle_project");
+        let result = analyse(LOG, project_dir);
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|message| message.error.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "AppTest > appHasAGreeting(): org.opentest4j.AssertionFailedError: expected: not <null>",
+                "LibTest > sums(): org.opentest4j.AssertionFailedError"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_read_reports_of_nested_modules() {
+        static LOG: &str = include_str!("../../tests/gradle_test_nested.log");
+        let project_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/gradle_project");
+        let result = analyse(LOG, project_dir);
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "RouteTest > returnsNotFound(): org.opentest4j.AssertionFailedError: expected: <404> but was: <200>".to_string(),
+                locations: vec![types::Location {
+                    path: format!(
+                        "{project_dir}/services/api/src/test/java/org/example/api/RouteTest.java"
+                    ),
+                    row: 21,
+                    col: 0
+                }]
+            }]
+        );
+    }
+
     #[test]
     fn should_find_test_error_in_kotlin() {
         static LOG: &str = include_str!("../../tests/gradle_kotlin.log");
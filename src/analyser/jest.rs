@@ -0,0 +1,180 @@
+use crate::{
+    analyser::node::{parse_stack_frame, to_project_path},
+    core::types,
+};
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Jest`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+    let mut current_file = None;
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(file) = line.trim().strip_prefix("FAIL ") {
+                current_file = Some(file.trim());
+            }
+
+            if let Some(title) = line.trim().strip_prefix("● ") {
+                if title.starts_with("Console") {
+                    continue;
+                }
+                if let Some(error) =
+                    parse_failure(title, &lines[i + 1..], current_file, project_dir)
+                {
+                    // Failures are repeated in "Summary of all failing tests"
+                    if !errors.contains(&error) {
+                        errors.push(error);
+                    }
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses the lines after a failure title
+///
+/// Gets input like:
+/// "`  ● math › adds numbers`"
+///       ------------------- title
+/// ""
+/// "`    expect(received).toBe(expected) // Object.is equality`"
+///       ---------------------------------------------------- message
+/// ""
+/// "`    Expected: 4`"
+/// "`    Received: 3`"
+/// ""
+/// "`    > 12 |     expect(sum(1, 2)).toBe(4);`"
+///         -- row when there is no stack frame
+/// "`         |                       ^`"
+///                                    - col when there is no stack frame
+/// ""
+/// "`      at Object.<anonymous> (src/sum.test.ts:12:23)`"
+///                               ------------------- `parse_stack_frame`
+fn parse_failure(
+    title: &str,
+    lines: &[&str],
+    current_file: Option<&str>,
+    project_dir: &str,
+) -> Option<types::Message> {
+    let mut message = None;
+    let mut expectations = vec![];
+    let mut pointer = None;
+    let mut locations = vec![];
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("● ")
+            || trimmed.starts_with("FAIL ")
+            || trimmed.starts_with("PASS ")
+            || trimmed.starts_with("Test Suites:")
+            || trimmed == "Summary of all failing tests"
+        {
+            break;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with("at ") {
+            if let Some(location) = parse_stack_frame(trimmed, project_dir) {
+                locations.push(location);
+            }
+        } else if let Some(code) = trimmed.strip_prefix("> ") {
+            pointer = parse_pointer(code, lines.get(i + 1).copied());
+        } else if trimmed.starts_with("Expected") || trimmed.starts_with("Received") {
+            expectations.push(trimmed);
+        } else if message.is_none() {
+            message = Some(trimmed);
+        }
+    }
+
+    if locations.is_empty() {
+        let (row, col) = pointer?;
+        locations.push(types::Location {
+            path: to_project_path(current_file?, project_dir)?,
+            row,
+            col,
+        });
+    }
+
+    let mut error = format!("{title}: {}", message?);
+    if !expectations.is_empty() {
+        error = format!("{error} ({})", expectations.join(", "));
+    }
+
+    Some(types::Message { error, locations })
+}
+
+/// Parses the code frame pointer and the caret below it
+///
+/// Gets input like "`3 | foo(;`" and "`    |     ^`".
+/// The number is the row and the position of the caret below the code is the col.
+fn parse_pointer(code: &str, caret_line: Option<&str>) -> Option<(usize, usize)> {
+    let (row, _) = code.split_once(" |")?;
+    let row = row.trim().parse().ok()?;
+
+    let col = caret_line
+        .and_then(|line| {
+            let bar = line.find('|')?;
+            let caret = line.find('^')?;
+            caret.checked_sub(bar + 1)
+        })
+        .unwrap_or_default();
+
+    Some((row, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::jest::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_failed_tests() {
+        static LOG: &str = include_str!("../../tests/jest_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "math › adds numbers: expect(received).toBe(expected) // Object.is equality (Expected: 4, Received: 3)".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/sum.test.ts".to_string(),
+                        row: 12,
+                        col: 23
+                    }]
+                },
+                types::Message {
+                    error: "math › reads value: TypeError: Cannot read properties of undefined (reading 'x')".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/read.ts".to_string(),
+                            row: 4,
+                            col: 12
+                        },
+                        types::Location {
+                            path: "/tmp/project/src/sum.test.ts".to_string(),
+                            row: 18,
+                            col: 5
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "Test suite failed to run: SyntaxError: /tmp/project/src/broken.test.js: Unexpected token (3:4)".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/broken.test.js".to_string(),
+                        row: 3,
+                        col: 5
+                    }]
+                }
+            ]
+        );
+    }
+}
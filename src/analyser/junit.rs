@@ -0,0 +1,361 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use roxmltree::{Document, Node};
+
+use crate::{analyser::node, core::types};
+
+/// Directories that contain build output or dependencies instead of sources
+const SKIPPED_DIRS: [&str; 5] = [".git", "build", "node_modules", "target", "vendor"];
+
+/// A failed or errored testcase of a `JUnit` XML report
+#[derive(Debug, PartialEq, Eq)]
+pub struct Failure {
+    /// The class or file that contains the test like "`org.example.AppTest`"
+    pub classname: String,
+    /// The name of the test
+    pub name: String,
+    /// The message of the failure
+    pub message: String,
+    /// The text of the failure, for most tools a stack trace
+    pub details: String,
+    /// The file that some tools add to the testcase
+    pub file: Option<String>,
+    /// The line that some tools add to the testcase
+    pub line: Option<usize>,
+}
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Junit`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    // Many failures are in the same test class, so every file is only searched once
+    let mut found: HashMap<PathBuf, String> = HashMap::new();
+
+    failures(log)
+        .into_iter()
+        // A failure without a location is still reported like the bare messages of dotnet
+        .map(|failure| types::Message {
+            error: format!("{}: {}", failure.name, failure.message),
+            locations: locations(&failure, project_dir, &mut found),
+        })
+        .collect()
+}
+
+/// Returns the locations of a failure
+///
+/// The stack trace is preferred over the "file" and "line" attributes because
+/// it points to the failed assertion instead of the start of the test.
+fn locations(
+    failure: &Failure,
+    project_dir: &str,
+    found: &mut HashMap<PathBuf, String>,
+) -> Vec<types::Location> {
+    let mut locations = failure
+        .details
+        .lines()
+        .filter_map(|line| {
+            parse_jvm_frame(line, &failure.classname, project_dir, found)
+                .or_else(|| node::parse_stack_frame(line, project_dir))
+        })
+        .collect::<Vec<_>>();
+    let mut file_lines = failure
+        .details
+        .lines()
+        .filter_map(|line| parse_file_line(line, project_dir))
+        .collect::<Vec<_>>();
+    // Python tracebacks like the ones of pytest start with the outermost frame
+    let is_python = file_lines.iter().all(|location| {
+        Path::new(&location.path)
+            .extension()
+            .is_some_and(|e| e == "py")
+    });
+    if is_python {
+        file_lines.reverse();
+    }
+    locations.extend(file_lines);
+    locations.dedup();
+
+    if locations.is_empty() {
+        if let Some(file) = &failure.file {
+            locations.push(types::Location {
+                path: to_path(file, project_dir),
+                row: failure.line.unwrap_or_default(),
+                col: 0,
+            });
+        }
+    }
+
+    locations
+}
+
+/// Parses a frame of the test class in a java stack trace
+///
+/// Gets input like:
+/// "`at org.example.AppTest.appHasAGreeting(AppTest.java:13)`"
+///      -------------------                 ------------ --
+///      classname                           file         row
+///
+/// The file is searched in the project because the source set is unknown.
+/// When it is not found the test source set of the language like "src/test/kotlin" is used.
+fn parse_jvm_frame(
+    line: &str,
+    classname: &str,
+    project_dir: &str,
+    found: &mut HashMap<PathBuf, String>,
+) -> Option<types::Location> {
+    let frame = line.trim().strip_prefix("at ")?;
+    let frame = frame.rsplit_once('/').map_or(frame, |(_, frame)| frame);
+    if classname.is_empty() || !frame.starts_with(&format!("{classname}.")) {
+        return None;
+    }
+    let (_, location) = frame.rsplit_once('(')?;
+    let (file, row) = location.strip_suffix(')')?.rsplit_once(':')?;
+
+    let package = classname
+        .rsplit_once('.')
+        .map_or(String::new(), |(package, _)| package.replace('.', "/"));
+    let relative = Path::new(&package).join(file);
+    let path = found
+        .entry(relative)
+        .or_insert_with_key(|relative| {
+            find_file(Path::new(project_dir), relative).map_or_else(
+                || {
+                    let language = match relative.extension().and_then(|e| e.to_str()) {
+                        Some("kt") => "kotlin",
+                        Some("scala") => "scala",
+                        Some("groovy") => "groovy",
+                        _ => "java",
+                    };
+                    format!(
+                        "{project_dir}/src/test/{language}/{}",
+                        relative.to_string_lossy()
+                    )
+                },
+                |path| path.to_string_lossy().to_string(),
+            )
+        })
+        .clone();
+
+    Some(types::Location {
+        path,
+        row: row.parse().ok()?,
+        col: 0,
+    })
+}
+
+/// Parses a location at the start of a line
+///
+/// Gets input like:
+/// "`tests/test_math.py:5: AssertionError`" or "`    hello_test.go:11: got 3, wanted 4`"
+///   ------------------ -                           ------------- --
+///   path               row                         path          row
+fn parse_file_line(line: &str, project_dir: &str) -> Option<types::Location> {
+    let location = line.split_whitespace().next()?.trim_end_matches(':');
+    let mut splits = location.splitn(3, ':');
+    // Words like "Expected:" are no files
+    let path = splits
+        .next()
+        .filter(|path| Path::new(path).extension().is_some())?;
+    let row = splits.next()?.parse().ok()?;
+    let col = splits.next().and_then(|col| col.parse().ok()).unwrap_or(0);
+
+    Some(types::Location {
+        path: to_path(path, project_dir),
+        row,
+        col,
+    })
+}
+
+/// Searches a file that ends with the relative path in the project
+fn find_file(dir: &Path, relative: &Path) -> Option<PathBuf> {
+    let mut entries = fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter_map(|entry| Some((entry.path(), entry.file_type().ok()?)))
+        .collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    // The file type does not follow symlinks, so a link to a parent can not loop
+    entries.iter().find_map(|(path, file_type)| {
+        if file_type.is_dir() {
+            let name = path.file_name()?.to_string_lossy();
+            if SKIPPED_DIRS.contains(&name.as_ref()) {
+                return None;
+            }
+            find_file(path, relative)
+        } else {
+            path.ends_with(relative).then(|| path.clone())
+        }
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    format!("{project_dir}/{path}")
+}
+
+/// Returns all failed testcases of a report
+///
+/// The report can contain a single "testsuite" or "testsuites" with many of them
+#[must_use]
+pub fn failures(xml: &str) -> Vec<Failure> {
+    let Ok(document) = Document::parse(xml) else {
+        return vec![];
+    };
+
+    document
+        .descendants()
+        .filter(|node| node.has_tag_name("testcase"))
+        .filter_map(|testcase| parse_testcase(&testcase))
+        .collect()
+}
+
+fn parse_testcase(testcase: &Node) -> Option<Failure> {
+    let failure = testcase
+        .children()
+        .find(|node| node.has_tag_name("failure") || node.has_tag_name("error"))?;
+    let details = failure.text().unwrap_or_default().trim().to_string();
+
+    // The message attribute is optional, the type is the last resort
+    let message = failure
+        .attribute("message")
+        .map(str::to_string)
+        .or_else(|| details.lines().next().map(str::to_string))
+        .or_else(|| failure.attribute("type").map(str::to_string))
+        .unwrap_or_default();
+
+    Some(Failure {
+        classname: testcase
+            .attribute("classname")
+            .unwrap_or_default()
+            .to_string(),
+        name: testcase.attribute("name").unwrap_or_default().to_string(),
+        message: message.trim().to_string(),
+        details,
+        file: testcase.attribute("file").map(str::to_string),
+        line: testcase
+            .attribute("line")
+            .and_then(|line| line.parse().ok()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::junit::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_failures() {
+        static LOG: &str = include_str!("../../tests/junit_1.xml");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "appHasAGreeting: expected: not <null>".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/test/java/org/example/AppTest.java".to_string(),
+                        row: 13,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "test_add: assert 3 == 4".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/tests/test_math.py".to_string(),
+                        row: 5,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "test_load: ValueError: no user".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/app/user.py".to_string(),
+                            row: 8,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/tests/test_user.py".to_string(),
+                            row: 12,
+                            col: 0
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "math adds numbers: Error: expect(received).toBe(expected) // Object.is equality".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/sum.test.ts".to_string(),
+                        row: 12,
+                        col: 23
+                    }]
+                },
+                types::Message {
+                    error: "TestAdd: Failed".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/hello_test.go".to_string(),
+                        row: 11,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Cart total: expected 4 got 3".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/spec/cart_spec.rb".to_string(),
+                        row: 4,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Tests.CartTests.Total: Assert.Equal() Failure".to_string(),
+                    locations: vec![]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_test_sources_in_the_project() {
+        static LOG: &str = include_str!("../../tests/junit_2.xml");
+        let project_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/junit_project");
+        let result = analyse(LOG, project_dir);
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "adds(): expected: <4> but was: <3>".to_string(),
+                    locations: vec![types::Location {
+                        path: format!("{project_dir}/core/src/test/kotlin/org/example/CoreTest.kt"),
+                        row: 8,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "subtracts(): expected: <0> but was: <1>".to_string(),
+                    locations: vec![types::Location {
+                        path: format!("{project_dir}/core/src/test/kotlin/org/example/CoreTest.kt"),
+                        row: 12,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "loads(): file missing".to_string(),
+                    locations: vec![types::Location {
+                        path: format!("{project_dir}/src/test/kotlin/org/example/GoneTest.kt"),
+                        row: 5,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+}
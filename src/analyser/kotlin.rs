@@ -0,0 +1,165 @@
+use std::path::Path;
+
+use crate::core::{types, uri};
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Kotlin`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_short(line, project_dir) {
+                errors.push(error);
+            }
+            if let Some(error) = parse_line(line, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses the format that kotlinc uses in build tools
+///
+/// Gets input like:
+/// "`e: file:///tmp/project/src/A.kt:12:5 Unresolved reference: greet`"
+///   -  ------------------------------- --------------------------
+///   |  location                        message
+///   severity
+///
+/// Kotlin before 1.8 prints the location like "`/tmp/project/src/A.kt: (12, 5): message`"
+fn parse_short(line: &str, project_dir: &str) -> Option<types::Message> {
+    let (severity, rest) = line.split_once(": ")?;
+    let severity = match severity {
+        "e" => "error",
+        "w" => "warning",
+        _ => return None,
+    };
+
+    let (location, message) = if rest.starts_with("file://") {
+        let (location, message) = rest.split_once(' ')?;
+        let mut splits = location.rsplitn(3, ':');
+        let col = splits.next()?.parse().ok()?;
+        let row = splits.next()?.parse().ok()?;
+        let path = uri::file_uri_to_path(splits.next()?)?;
+        (types::Location { path, row, col }, message)
+    } else {
+        let (path, rest) = rest.split_once(": (")?;
+        let (position, message) = rest.split_once("): ")?;
+        let (row, col) = position.split_once(", ")?;
+        let location = types::Location {
+            path: to_path(path, project_dir),
+            row: row.parse().ok()?,
+            col: col.parse().ok()?,
+        };
+        (location, message)
+    };
+
+    Some(types::Message {
+        error: format!("{severity}: {message}"),
+        locations: vec![location],
+    })
+}
+
+/// Parses the format of kotlinc 2
+///
+/// Gets input like:
+/// "`src/main.kt:3:5: error: unresolved reference 'printn'.`"
+///   ----------- - -  -------------------------------------
+///   path        | col message
+///               row
+fn parse_line(line: &str, project_dir: &str) -> Option<types::Message> {
+    let mut splits = line.splitn(4, ':');
+    let path = splits.next()?;
+    let row = splits.next()?.parse().ok()?;
+    let col = splits.next()?.parse().ok()?;
+    let message = splits.next()?.trim_start();
+
+    let is_kotlin = Path::new(path).extension().is_some_and(|extension| {
+        extension.eq_ignore_ascii_case("kt") || extension.eq_ignore_ascii_case("kts")
+    });
+    if !is_kotlin || !(message.starts_with("error: ") || message.starts_with("warning: ")) {
+        return None;
+    }
+
+    Some(types::Message {
+        error: message.to_string(),
+        locations: vec![types::Location {
+            path: to_path(path, project_dir),
+            row,
+            col,
+        }],
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::kotlin::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_errors() {
+        static LOG: &str = include_str!("../../tests/kotlin_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: Unresolved reference: greet".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/src/main/kotlin/My App.kt".to_string(),
+                        row: 12,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "warning: Variable 'x' is never used".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/src/main/kotlin/Util.kt".to_string(),
+                        row: 3,
+                        col: 9
+                    }]
+                },
+                types::Message {
+                    error: "error: Type mismatch: inferred type is String but Int was expected"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/src/main/kotlin/Legacy.kt".to_string(),
+                        row: 7,
+                        col: 14
+                    }]
+                },
+                types::Message {
+                    error: "error: unresolved reference 'printn'.".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main.kt".to_string(),
+                        row: 3,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "warning: parameter 'args' is never used.".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main.kt".to_string(),
+                        row: 8,
+                        col: 9
+                    }]
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use std::path::Path;
+
+use crate::core::types;
+
+/// Names of linkers that prefix their output like "`/usr/bin/ld: `"
+const LINKERS: [&str; 8] = [
+    "ld", "ld.bfd", "ld.gold", "ld.lld", "ld64.lld", "lld", "rust-lld", "mold",
+];
+
+/// Extensions of files without source locations
+const OBJECTS: [&str; 8] = ["o", "obj", "a", "so", "lib", "dll", "dylib", "rlib"];
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Linker`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            errors.extend(parse(line, &lines[i + 1..], project_dir));
+        }
+    }
+
+    errors
+}
+
+/// Parses a block of linker errors that starts at the line
+///
+/// Used by the analysers of compilers that call a linker like clang, gcc, cargo and zig
+#[must_use]
+pub fn parse(line: &str, lines: &[&str], project_dir: &str) -> Vec<types::Message> {
+    let line = clean(line);
+
+    if let Some(function) = parse_function(line) {
+        return lines
+            .iter()
+            .map(|line| clean(line))
+            .take_while(|line| parse_function(line).is_none() && is_reference(line))
+            .filter_map(|line| parse_reference(line, function, project_dir))
+            .collect();
+    }
+
+    parse_symbol(line, lines, project_dir).into_iter().collect()
+}
+
+/// Checks for lines that are printed by a linker like "``/usr/bin/ld: main.o: in function `main':``"
+///
+/// The references of gnu ld like "``main.c:5: undefined reference to `foo'``" have no prefix
+#[must_use]
+pub fn is_linker(line: &str) -> bool {
+    let line = clean(line);
+
+    strip_linker(line).is_some()
+        || line.contains(": undefined reference to ")
+        || line.contains(": multiple definition of ")
+}
+
+/// Removes the indentation and the note prefixes of rustc and zig
+fn clean(line: &str) -> &str {
+    let line = line.trim();
+    line.strip_prefix("= note: ")
+        .or_else(|| line.strip_prefix("note: "))
+        .unwrap_or(line)
+        .trim_start()
+}
+
+/// Removes the name of the linker
+///
+/// Gets input like:
+/// "``/usr/bin/ld: main.o: in function `main':``"
+///    -----------  -------------------------
+///    linker       rest
+fn strip_linker(line: &str) -> Option<&str> {
+    let (linker, rest) = line.split_once(": ")?;
+    let name = linker.rsplit('/').next().unwrap_or(linker);
+
+    LINKERS.contains(&name).then_some(rest)
+}
+
+/// Parses the function of a gnu ld block
+///
+/// Gets input like:
+/// "``/usr/bin/ld: /tmp/ccX.o: in function `main':``"
+///                                         ------
+///                                         function
+fn parse_function(line: &str) -> Option<&str> {
+    let (_, function) = line.split_once(": in function ")?;
+
+    function.strip_suffix(':')
+}
+
+/// Checks if a line starts with a location like "`main.c:(.text+0x1a): `"
+fn is_reference(line: &str) -> bool {
+    let line = strip_linker(line).unwrap_or(line);
+
+    line.split_once(": ")
+        .is_some_and(|(location, _)| location.contains(':'))
+}
+
+/// Parses a reference of a gnu ld block
+///
+/// Gets input like:
+/// "``/tmp/project/main.c:5: undefined reference to `foo'``"
+///    ------------------- -  ---------------------------
+///    path                row message
+///
+/// Without debug info the row is missing like "``main.c:(.text+0x1a): undefined reference to `foo'``"
+fn parse_reference(line: &str, function: &str, project_dir: &str) -> Option<types::Message> {
+    let line = strip_linker(line).unwrap_or(line);
+    let (location, message) = line.split_once(": ")?;
+    let (path, row) = location.split_once(':')?;
+    let row = row.split(':').next().unwrap_or_default();
+
+    Some(types::Message {
+        error: format!("error: {message} in function {function}"),
+        locations: vec![types::Location {
+            path: to_source_path(path, project_dir)?,
+            row: row.parse().unwrap_or_default(),
+            col: 0,
+        }],
+    })
+}
+
+/// Parses a lld block
+///
+/// Gets input like:
+/// "`ld.lld: error: undefined symbol: foo`"
+///                  --------------------- message
+///
+/// Followed by references like "`>>> referenced by main.c:5 (/tmp/project/main.c:5)`"
+fn parse_symbol(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    // zig prints "error: ld.lld: undefined symbol: foo"
+    let line = strip_linker(line).unwrap_or(line);
+    let line = line.strip_prefix("error: ").unwrap_or(line);
+    let message = strip_linker(line).unwrap_or(line);
+    if !message.starts_with("undefined symbol: ") && !message.starts_with("duplicate symbol: ") {
+        return None;
+    }
+
+    let locations = lines
+        .iter()
+        .map(|line| clean(line))
+        .take_while(|line| line.starts_with(">>>") || line.starts_with("referenced by "))
+        .filter_map(|line| parse_symbol_location(line, project_dir))
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(types::Message {
+        error: format!("error: {message}"),
+        locations,
+    })
+}
+
+/// Parses a reference of lld
+///
+/// Gets input like:
+/// "`>>> referenced by main.c:5 (/tmp/project/main.c:5)`"
+///                               ------------------- -
+///                               path                row
+///
+/// The short path before the parens is used when there are none
+fn parse_symbol_location(line: &str, project_dir: &str) -> Option<types::Location> {
+    let line = line.trim_start_matches('>').trim();
+    let location = line
+        .strip_prefix("referenced by ")
+        .or_else(|| line.strip_prefix("defined at "))?;
+    let location = location
+        .split_once(" (")
+        .and_then(|(_, full)| full.strip_suffix(')'))
+        .unwrap_or(location);
+    let (path, row) = location.rsplit_once(':')?;
+
+    Some(types::Location {
+        path: to_source_path(path, project_dir)?,
+        row: row.parse().ok()?,
+        col: 0,
+    })
+}
+
+/// Returns the full path of a source file
+///
+/// Object files and libraries have no lines so they are ignored
+fn to_source_path(path: &str, project_dir: &str) -> Option<String> {
+    let extension = Path::new(path).extension()?;
+    if OBJECTS
+        .iter()
+        .any(|object| extension.eq_ignore_ascii_case(object))
+    {
+        return None;
+    }
+
+    if path.starts_with('/') {
+        Some(path.to_string())
+    } else {
+        Some(format!("{project_dir}/{path}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::linker::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_gnu_ld_errors() {
+        static LOG: &str = include_str!("../../tests/linker_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: undefined reference to `tokenize' in function `parse'"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/parser.c".to_string(),
+                        row: 27,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: undefined reference to `tokenize' in function `parse'"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/parser.c".to_string(),
+                        row: 31,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: undefined reference to `render' in function `main'"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/main.c".to_string(),
+                        row: 0,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: multiple definition of `init'; /tmp/ccQ2.o:/tmp/project/src/config.c:3: first defined here in function `init'".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/app.c".to_string(),
+                        row: 9,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_lld_errors() {
+        static LOG: &str = include_str!("../../tests/linker_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: undefined symbol: render".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/main.c".to_string(),
+                            row: 12,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/src/view.c".to_string(),
+                            row: 40,
+                            col: 0
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "error: duplicate symbol: config".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/app.c".to_string(),
+                            row: 9,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/src/config.c".to_string(),
+                            row: 3,
+                            col: 0
+                        }
+                    ]
+                }
+            ]
+        );
+    }
+}
@@ -8,27 +8,75 @@ pub mod biome;
 pub mod cargo;
 /// Contains the analyser code for the [`crate::config::ParserKind::Clang`]
 pub mod clang;
+/// Contains the analyser code for the [`crate::config::ParserKind::Dotnet`]
+pub mod dotnet;
 /// Contains the analyser code for the [`crate::config::ParserKind::Dune`]
 pub mod dune;
+/// Contains the analyser code for the [`crate::config::ParserKind::Elixir`]
+pub mod elixir;
+/// Contains the analyser code for the [`crate::config::ParserKind::Esbuild`]
+pub mod esbuild;
 /// Contains the analyser code for the [`crate::config::ParserKind::Eslint`]
 pub mod eslint;
 /// Contains the analyser code for the [`crate::config::ParserKind::Gcc`]
 pub mod gcc;
+/// Contains the analyser code for the [`crate::config::ParserKind::Ghc`]
+pub mod ghc;
 /// Contains the analyser code for the [`crate::config::ParserKind::Go`]
 pub mod go;
 /// Contains the analyser code for the [`crate::config::ParserKind::Gradle`]
 pub mod gradle;
 /// Contains the analyser code for the [`crate::config::ParserKind::Java`]
 pub mod java;
+/// Contains the analyser code for the [`crate::config::ParserKind::Jest`]
+pub mod jest;
+/// Contains the analyser code for the [`crate::config::ParserKind::Junit`]
+pub mod junit;
 /// Contains the analyser code for the [`crate::config::ParserKind::KarmaJasmine`]
 pub mod karma_jasmine;
+/// Contains the analyser code for the [`crate::config::ParserKind::Kotlin`]
+pub mod kotlin;
+/// Contains the analyser code for the [`crate::config::ParserKind::Linker`]
+pub mod linker;
 /// Contains the analyser code for the [`crate::config::ParserKind::Maven`]
 pub mod maven;
+/// Contains the analyser code for the [`crate::config::ParserKind::Mypy`]
+pub mod mypy;
 /// Contains the analyser code for the [`crate::config::ParserKind::Nix`]
 pub mod nix;
+/// Contains the analyser code for the [`crate::config::ParserKind::Node`]
+pub mod node;
 /// Contains the analyser code for the [`crate::config::ParserKind::Odin`]
 pub mod odin;
+/// Contains the analyser code for the [`crate::config::ParserKind::Phpstan`]
+pub mod phpstan;
+/// Contains the analyser code for the [`crate::config::ParserKind::Phpunit`]
+pub mod phpunit;
+/// Contains the analyser code for the [`crate::config::ParserKind::Pyright`]
+pub mod pyright;
+/// Contains the analyser code for the [`crate::config::ParserKind::Pytest`]
+pub mod pytest;
+/// Contains the analyser code for the [`crate::config::ParserKind::Python`]
+pub mod python;
+/// Contains the analyser code for the [`crate::config::ParserKind::Rspec`]
+pub mod rspec;
+/// Contains the analyser code for the [`crate::config::ParserKind::Rubocop`]
+pub mod rubocop;
+/// Contains the analyser code for the [`crate::config::ParserKind::Ruff`]
+pub mod ruff;
+/// Contains the analyser code for the [`crate::config::ParserKind::Scala`]
+pub mod scala;
+/// Maps javascript locations back to their original source
+pub mod sourcemap;
+/// Contains the analyser code for the [`crate::config::ParserKind::Swift`]
+pub mod swift;
+/// Contains the analyser code for the [`crate::config::ParserKind::Tsc`]
+pub mod tsc;
 /// Contains the analyser code for the [`crate::config::ParserKind::C`]
 pub mod v;
+/// Contains the analyser code for the [`crate::config::ParserKind::Vitest`]
+pub mod vitest;
+/// Contains the analyser code for the [`crate::config::ParserKind::Webpack`]
+pub mod webpack;
 /// Contains the analyser code for the [`crate::config::ParserKind::Zig`]
 pub mod zig;
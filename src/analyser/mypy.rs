@@ -0,0 +1,121 @@
+use crate::core::types;
+
+const SEVERITIES: [&str; 3] = ["error", "warning", "note"];
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Mypy`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = &lines.len();
+
+    for i in 0..*line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_line(line, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parse message from line
+///
+/// Gets line input like:
+/// "`app/models/user.py:33:9: error: "User" has no attribute "nmae"; maybe "name"?  [attr-defined]`"
+///   ------------------ -- -  -----  --------------------------------------------   ------------
+///   path               |  |  |      message                                        code
+///                      |  |  severity
+///                      |  col (only with --show-column-numbers)
+///                      row
+fn parse_line(line: &str, project_dir: &str) -> Option<types::Message> {
+    let (location, rest) = SEVERITIES.iter().find_map(|severity| {
+        let (location, message) = line.split_once(&format!(": {severity}: "))?;
+        Some((location, format!("{severity}: {message}")))
+    })?;
+
+    let mut splits = location.split(':');
+    let path = splits.next()?;
+    let row = splits.next()?.parse().ok()?;
+    let col = splits
+        .next()
+        .and_then(|col| col.parse().ok())
+        .unwrap_or_default();
+
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    // The code is separated by two spaces
+    let error = match rest.rsplit_once("  [") {
+        Some((message, code)) => format!("{message} [{code}"),
+        None => rest,
+    };
+
+    Some(types::Message {
+        error,
+        locations: vec![types::Location { path, row, col }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::mypy::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn error() {
+        static LOG: &str = include_str!("../../tests/mypy_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: Incompatible types in assignment (expression has type \"int\", variable has type \"str\") [assignment]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/main.py".to_string(),
+                        row: 12,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "note: Revealed type is \"builtins.int\"".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/main.py".to_string(),
+                        row: 14,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: \"User\" has no attribute \"nmae\"; maybe \"name\"? [attr-defined]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/models/user.py".to_string(),
+                        row: 33,
+                        col: 9
+                    }]
+                },
+                types::Message {
+                    error: "error: Function is missing a return type annotation [no-untyped-def]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/legacy.py".to_string(),
+                        row: 5,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "note: Use \"-> None\" if function does not return a value".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/legacy.py".to_string(),
+                        row: 5,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+}
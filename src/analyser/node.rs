@@ -0,0 +1,220 @@
+use std::path::Path;
+
+use crate::{
+    analyser::sourcemap,
+    core::{types, uri},
+};
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Node`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str, source_maps: bool) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 1..line_len {
+        if let Some(line) = lines.get(i) {
+            let starts_stack = is_frame(line) && !lines.get(i - 1).is_some_and(|l| is_frame(l));
+            if !starts_stack {
+                continue;
+            }
+            let Some(error) = lines[..i].iter().rev().find(|l| !l.trim().is_empty()) else {
+                continue;
+            };
+
+            let mut locations = lines[i..]
+                .iter()
+                .take_while(|line| is_frame(line))
+                .filter_map(|frame| parse_stack_frame(frame, project_dir))
+                .collect::<Vec<_>>();
+            if source_maps {
+                locations = locations.into_iter().map(sourcemap::resolve).collect();
+            }
+
+            if !locations.is_empty() {
+                errors.push(types::Message {
+                    error: error.trim().to_string(),
+                    locations,
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+fn is_frame(line: &str) -> bool {
+    line.trim_start().starts_with("at ")
+}
+
+/// Parses a V8 stack frame
+///
+/// Gets input like:
+/// "`at Object.<anonymous> (src/sum.test.ts:12:23)`" or "`at src/sum.test.ts:12:23`"
+///                          -------------------              -------------------
+///                          `parse_location`                 `parse_location`
+pub fn parse_stack_frame(frame: &str, project_dir: &str) -> Option<types::Location> {
+    let frame = frame.trim().strip_prefix("at ")?;
+    let frame = frame.strip_prefix("async ").unwrap_or(frame);
+    let location = match frame.rsplit_once(" (") {
+        Some((_, location)) => location.strip_suffix(')')?,
+        None => frame,
+    };
+
+    parse_location(location, project_dir)
+}
+
+/// Parses location
+///
+/// Gets location input like:
+/// "`src/sum.test.ts:12:23`" or "`file:///tmp/project/src/main.mjs:1:1`"
+///   --------------- -- --
+///   path            |  col
+///                   row
+///
+/// Frames of dependencies, of node itself and of files outside of the project are ignored
+pub fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
+    let mut splits = location.rsplitn(3, ':');
+    let col = splits.next()?.parse().ok()?;
+    let row = splits.next()?.parse().ok()?;
+    let path = splits.next()?;
+
+    Some(types::Location {
+        path: to_project_path(path, project_dir)?,
+        row,
+        col,
+    })
+}
+
+/// Returns the full path for a file of the project
+pub fn to_project_path(path: &str, project_dir: &str) -> Option<String> {
+    let path = if path.starts_with("file://") {
+        uri::file_uri_to_path(path)?
+    } else {
+        path.to_string()
+    };
+
+    if path.contains("node_modules")
+        || path.starts_with("node:")
+        || path.starts_with("internal/")
+        || path.starts_with('<')
+    {
+        return None;
+    }
+    if path.starts_with('/') {
+        // "/tmp/project2" is not part of "/tmp/project"
+        return Path::new(&path).starts_with(project_dir).then_some(path);
+    }
+
+    Some(format!("{project_dir}/{path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::node::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_stack_traces() {
+        static LOG: &str = include_str!("../../tests/node_1.log");
+        let result = analyse(LOG, "/tmp/project", false);
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "Error: boom".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/server.js".to_string(),
+                            row: 10,
+                            col: 11
+                        },
+                        types::Location {
+                            path: "/tmp/project/src/index.js".to_string(),
+                            row: 3,
+                            col: 1
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "TypeError: Cannot read properties of undefined (reading 'value')"
+                        .to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/my config.mjs".to_string(),
+                            row: 4,
+                            col: 22
+                        },
+                        types::Location {
+                            path: "/tmp/project/src/main.mjs".to_string(),
+                            row: 7,
+                            col: 1
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "error: Uncaught (in promise) Error: connection refused".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/db.ts".to_string(),
+                            row: 12,
+                            col: 9
+                        },
+                        types::Location {
+                            path: "/tmp/project/main.ts".to_string(),
+                            row: 3,
+                            col: 1
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "TypeError: undefined is not an object (evaluating 'x.y')".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/index.ts".to_string(),
+                        row: 2,
+                        col: 15
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_ignore_frames_outside_of_the_project() {
+        static LOG: &str = include_str!("../../tests/node_2.log");
+        let result = analyse(LOG, "/tmp/project", false);
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "Error: missing option port".to_string(),
+                locations: vec![types::Location {
+                    path: "/tmp/project/src/cli.js".to_string(),
+                    row: 8,
+                    col: 11
+                }]
+            }]
+        );
+    }
+
+    #[test]
+    fn should_map_source_maps() {
+        let project_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/sourcemap");
+        let log = format!("Error: boom\n    at start ({project_dir}/dist/index.js:2:11)\n");
+        let result = analyse(&log, project_dir, true);
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "Error: boom".to_string(),
+                locations: vec![types::Location {
+                    path: format!("{project_dir}/src/index.ts"),
+                    row: 4,
+                    col: 3
+                }]
+            }]
+        );
+    }
+}
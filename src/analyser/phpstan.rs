@@ -0,0 +1,185 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Phpstan`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let mut file: Option<&str> = None;
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(path) = line.trim().strip_prefix("Line ") {
+                file = Some(path.trim());
+                continue;
+            }
+            // Tables are separated by blank lines, rows of a later table are not in the file
+            if line.trim().is_empty() {
+                file = None;
+            }
+            if let Some(file) = file {
+                if let Some(error) = parse_row(line, &lines[i + 1..], file, project_dir) {
+                    errors.push(error);
+                }
+            }
+            if let Some(error) = parse_raw(line, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses a row of the table format
+///
+/// Gets input like:
+/// "`  12     Method App\Service\UserService::find() should return App\User`"
+///     --     ----------------------------------------------------------
+///     row    message
+///
+/// Followed by the continuation of the message and the "🪪  return.type" identifier
+fn parse_row(line: &str, lines: &[&str], file: &str, project_dir: &str) -> Option<types::Message> {
+    let row = parse_row_number(line)?;
+    let (_, message) = line.trim().split_once(char::is_whitespace)?;
+
+    let mut message = vec![message.trim()];
+    let mut identifier = None;
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('-') || parse_row_number(line).is_some() {
+            break;
+        }
+        if let Some(id) = trimmed.strip_prefix("🪪") {
+            identifier = Some(id.trim());
+        } else if !trimmed.starts_with("💡") {
+            message.push(trimmed);
+        }
+    }
+
+    let message = message.join(" ");
+    let error = match identifier {
+        Some(identifier) => format!("{message} [{identifier}]"),
+        None => message,
+    };
+
+    Some(types::Message {
+        error,
+        locations: vec![types::Location {
+            path: to_path(file, project_dir),
+            row,
+            col: 0,
+        }],
+    })
+}
+
+/// Returns the row of a table row that is not indented like a continuation line
+fn parse_row_number(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start().len();
+    if indent > 3 {
+        return None;
+    }
+
+    line.split_whitespace().next()?.parse().ok()
+}
+
+/// Parses the "--error-format=raw" output
+///
+/// Gets input like:
+/// "`src/Controller/HomeController.php:8:Call to an undefined method App\Foo::bar().`"
+///   --------------------------------- - ---------------------------------------
+///   path                              | message
+///                                     row
+fn parse_raw(line: &str, project_dir: &str) -> Option<types::Message> {
+    let (path, rest) = line.split_once(".php:")?;
+    let (row, message) = rest.split_once(':')?;
+
+    Some(types::Message {
+        error: message.trim().to_string(),
+        locations: vec![types::Location {
+            path: to_path(&format!("{path}.php"), project_dir),
+            row: row.parse().ok()?,
+            col: 0,
+        }],
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::phpstan::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_table_errors() {
+        static LOG: &str = include_str!("../../tests/phpstan_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "Method App\\Service\\UserService::find() should return App\\User but returns App\\User|null. [return.type]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Service/UserService.php".to_string(),
+                        row: 12,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Undefined variable: $count [variable.undefined]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Service/UserService.php".to_string(),
+                        row: 30,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Call to an undefined method App\\Foo::bar().".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Controller/HomeController.php".to_string(),
+                        row: 8,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_raw_errors() {
+        static LOG: &str = include_str!("../../tests/phpstan_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "Method App\\Service\\UserService::find() should return App\\User but returns App\\User|null.".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Service/UserService.php".to_string(),
+                        row: 12,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Call to an undefined method App\\Foo::bar().".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Controller/HomeController.php".to_string(),
+                        row: 8,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Phpunit`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_failure(line, &lines[i + 1..], project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses a numbered failure or error
+///
+/// Gets input like:
+/// "`1) App\Tests\MathTest::testAdd`"
+///      -------------------------
+///      test
+///
+/// Followed by the message and the frames like "`/tmp/project/tests/MathTest.php:10`"
+fn parse_failure(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let test = parse_header(line)?;
+    let message = lines.first()?.trim();
+
+    let locations = lines
+        .iter()
+        .take_while(|line| **line != "--" && parse_header(line).is_none())
+        .filter_map(|line| parse_frame(line, project_dir))
+        .collect::<Vec<_>>();
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(types::Message {
+        error: format!("{test}: {message}"),
+        locations,
+    })
+}
+
+fn parse_header(line: &str) -> Option<&str> {
+    let (number, test) = line.split_once(") ")?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) || !test.contains("::") {
+        return None;
+    }
+
+    Some(test)
+}
+
+/// Parses a frame
+///
+/// Gets input like:
+/// "`/tmp/project/tests/MathTest.php:10`"
+///   ------------------------------- --
+///   path                            row
+///
+/// Frames of dependencies in "vendor" are ignored
+fn parse_frame(line: &str, project_dir: &str) -> Option<types::Location> {
+    let (path, row) = line.trim().rsplit_once(':')?;
+    let is_php = Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("php"));
+    if !is_php || path.contains("/vendor/") {
+        return None;
+    }
+
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    Some(types::Location {
+        path,
+        row: row.parse().ok()?,
+        col: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::phpunit::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_failures() {
+        static LOG: &str = include_str!("../../tests/phpunit_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "App\\Tests\\UserTest::testLoad: RuntimeException: user not found"
+                        .to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/User.php".to_string(),
+                            row: 22,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/tests/UserTest.php".to_string(),
+                            row: 14,
+                            col: 0
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "App\\Tests\\MathTest::testConcat: Failed asserting that two strings are identical.".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/tests/MathTest.php".to_string(),
+                        row: 10,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::core::types;
+
+const SEVERITIES: [&str; 3] = ["error", "warning", "information"];
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Pyright`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_diagnostic(i, line, lines, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses a diagnostic with its continuation lines
+///
+/// Gets input like:
+/// "`  /tmp/project/app/main.py:12:5 - error: Type "Literal[1]" is not assignable to declared type "str"`"
+///     ---------------------------------   -----  ------------------------------------------------------
+///     `parse_location`                    |      message
+///                                         severity
+/// "`    "Literal[1]" is not assignable to "str" (reportAssignmentType)`"
+///       -------------------------------------  ---------------------
+///       message                                code
+fn parse_diagnostic(
+    start_index: usize,
+    line: &str,
+    lines: &[&str],
+    project_dir: &str,
+) -> Option<types::Message> {
+    let indent = line.len() - line.trim_start().len();
+    let (location, rest) = line.trim().split_once(" - ")?;
+    let (severity, message) = rest.split_once(": ")?;
+    if !SEVERITIES.contains(&severity) {
+        return None;
+    }
+    let location = parse_location(location, project_dir)?;
+
+    let mut message = message.to_string();
+    'continuation: for y in 1.. {
+        let Some(line) = lines.get(start_index + y) else {
+            break 'continuation;
+        };
+        if line.len() - line.trim_start().len() <= indent {
+            break 'continuation;
+        }
+        message.push(' ');
+        message.push_str(line.trim());
+    }
+
+    let error = match message.rsplit_once(" (") {
+        Some((message, code)) if code.starts_with("report") && code.ends_with(')') => {
+            format!("{severity}: {message} [{}]", &code[..code.len() - 1])
+        }
+        _ => format!("{severity}: {message}"),
+    };
+
+    Some(types::Message {
+        error,
+        locations: vec![location],
+    })
+}
+
+/// Parses location
+///
+/// Gets location input like:
+/// "`/tmp/project/app/main.py:12:5`"
+///   ------------------------ -- -
+///   path                     |  col
+///                            row
+fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
+    let mut splits = location.rsplitn(3, ':');
+    let col = splits.next()?.parse().ok()?;
+    let row = splits.next()?.parse().ok()?;
+    let path = splits.next()?;
+
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    Some(types::Location { path, row, col })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::pyright::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn error() {
+        static LOG: &str = include_str!("../../tests/pyright_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: Type \"Literal[1]\" is not assignable to declared type \"str\" \"Literal[1]\" is not assignable to \"str\" [reportAssignmentType]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/main.py".to_string(),
+                        row: 12,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "warning: Import \"requests_mock\" could not be resolved [reportMissingModuleSource]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/main.py".to_string(),
+                        row: 20,
+                        col: 8
+                    }]
+                },
+                types::Message {
+                    error: "error: Cannot access attribute \"nmae\" for class \"User*\" Attribute \"nmae\" is unknown [reportAttributeAccessIssue]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/models/user.py".to_string(),
+                        row: 33,
+                        col: 14
+                    }]
+                }
+            ]
+        );
+    }
+}
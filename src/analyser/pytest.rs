@@ -0,0 +1,233 @@
+use crate::{
+    analyser::python::{is_exception, parse_file_line, to_project_path},
+    core::types,
+};
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Pytest`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let mut titles: Vec<&str> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(title) = parse_section_title(line) {
+                titles.push(title);
+                if let Some(error) = parse_section(&lines[i + 1..], project_dir) {
+                    errors.push(error);
+                }
+            }
+            if let Some(error) = parse_summary(line, &titles, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses the title of a failure section
+///
+/// Gets input like:
+/// "`___________ TestUser.test_name ___________`"
+///              ------------------
+///              title
+fn parse_section_title(line: &str) -> Option<&str> {
+    let line = line.trim_end();
+    if !line.starts_with("__") || !line.ends_with('_') {
+        return None;
+    }
+    let title = line.trim_matches('_').trim();
+    // The separator between frames looks like "_ _ _ _"
+    if title.is_empty() || title.chars().all(|c| c == '_' || c == ' ') {
+        return None;
+    }
+
+    Some(title)
+}
+
+/// Parses the lines after a section title until the next section
+fn parse_section(lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let mut locations: Vec<types::Location> = vec![];
+    let mut messages: Vec<&str> = vec![];
+
+    for line in lines {
+        if line.starts_with("====")
+            || line.starts_with("!!!!")
+            || parse_section_title(line).is_some()
+        {
+            break;
+        }
+
+        if *line == "E" || line.starts_with("E ") {
+            let message = line[1..].trim();
+            if let Some(location) = parse_file_line(message, project_dir) {
+                locations.push(location);
+            } else if !message.is_empty() {
+                messages.push(message);
+            }
+            continue;
+        }
+
+        if let Some(location) = parse_frame(line, project_dir) {
+            locations.push(location);
+        }
+    }
+
+    // Frames are printed from the test to the place where the error was raised
+    locations.reverse();
+    locations.dedup();
+
+    if locations.is_empty() {
+        return None;
+    }
+
+    let error = messages
+        .iter()
+        .rev()
+        .find(|message| is_exception(message))
+        .or_else(|| messages.first())?;
+
+    Some(types::Message {
+        error: (*error).to_string(),
+        locations,
+    })
+}
+
+/// Parses a line from the "short test summary info" that has no failure section
+///
+/// Gets input like:
+/// "`FAILED tests/test_user.py::TestUser::test_name - ValueError: no greeting`"
+///          ------------------  ------------------   -----------------------
+///          path                test                 message
+fn parse_summary(line: &str, titles: &[&str], project_dir: &str) -> Option<types::Message> {
+    let rest = line
+        .strip_prefix("FAILED ")
+        .or_else(|| line.strip_prefix("ERROR "))?;
+    let (node, message) = rest.split_once(" - ").unwrap_or((rest, rest));
+    let (path, test) = node.split_once("::").unwrap_or((node, ""));
+    let test = test.replace("::", ".");
+
+    let covered = titles.iter().any(|title| {
+        (!test.is_empty() && (*title == test || title.ends_with(&format!(" {test}"))))
+            || *title == format!("ERROR collecting {path}")
+    });
+    if covered {
+        return None;
+    }
+
+    Some(types::Message {
+        error: message.to_string(),
+        locations: vec![types::Location {
+            path: to_project_path(path, project_dir)?,
+            row: 0,
+            col: 0,
+        }],
+    })
+}
+
+/// Parses a traceback frame of pytest
+///
+/// Gets input like:
+/// "`tests/test_math.py:5: AssertionError`"
+///   ------------------ -
+///   path               row
+fn parse_frame(line: &str, project_dir: &str) -> Option<types::Location> {
+    let (path, rest) = line.split_once(".py:")?;
+    if path.contains(' ') {
+        return None;
+    }
+    let (row, _) = rest.split_once(':')?;
+
+    Some(types::Location {
+        path: to_project_path(&format!("{path}.py"), project_dir)?,
+        row: row.parse().ok()?,
+        col: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::pytest::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_failed_tests() {
+        static LOG: &str = include_str!("../../tests/pytest_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "ModuleNotFoundError: No module named 'missing_module'".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/tests/test_broken.py".to_string(),
+                        row: 1,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "assert 3 == 4".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/tests/test_math.py".to_string(),
+                        row: 5,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "ValueError: no greeting".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/app/user.py".to_string(),
+                            row: 8,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/tests/test_user.py".to_string(),
+                            row: 12,
+                            col: 0
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "AssertionError: assert [1, 2, 3] == [1, 2, 4]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/tests/test_math.py".to_string(),
+                        row: 9,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Failed: Timeout >1.0s".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/tests/test_slow.py".to_string(),
+                        row: 0,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_collection_syntax_error() {
+        static LOG: &str = include_str!("../../tests/pytest_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "SyntaxError: '(' was never closed".to_string(),
+                locations: vec![types::Location {
+                    path: "/tmp/project/tests/test_syntax.py".to_string(),
+                    row: 3,
+                    col: 0
+                }]
+            }]
+        );
+    }
+}
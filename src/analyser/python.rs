@@ -0,0 +1,191 @@
+use std::path::Path;
+
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Python`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if line.trim() == "Traceback (most recent call last):" {
+                let indent = line.len() - line.trim_start().len();
+                if let Some(error) = parse_traceback(&lines[i + 1..], indent, project_dir) {
+                    errors.push(error);
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses the frames and the exception after "Traceback (most recent call last):"
+///
+/// Gets input like:
+///   File "/tmp/project/app/main.py", line 18, in main
+///     config = load("config.json")
+///              ^^^^^^^^^^^^^^^^^^^
+///   File "/tmp/project/app/config.py", line 16, in load
+///     raise `ConfigError`(f"invalid config {path}")
+/// app.config.ConfigError: invalid config config.json
+/// ------------------------------------------------- error
+///
+/// The frames are returned with the innermost frame first
+fn parse_traceback(lines: &[&str], indent: usize, project_dir: &str) -> Option<types::Message> {
+    let mut locations = vec![];
+
+    for line in lines {
+        let Some(line) = line.get(indent..) else {
+            break;
+        };
+        if line.trim().is_empty() {
+            break;
+        }
+        if line.starts_with(char::is_whitespace) {
+            if let Some(location) = parse_file_line(line, project_dir) {
+                locations.push(location);
+            }
+            continue;
+        }
+
+        if locations.is_empty() {
+            return None;
+        }
+        locations.reverse();
+
+        return Some(types::Message {
+            error: line.trim().to_string(),
+            locations,
+        });
+    }
+
+    None
+}
+
+/// Parses a frame of a python traceback
+///
+/// Gets input like:
+/// "`  File "/tmp/project/app/main.py", line 18, in main`"
+///           ------------------------        --
+///           path                            row
+pub fn parse_file_line(line: &str, project_dir: &str) -> Option<types::Location> {
+    let rest = line.trim().strip_prefix("File \"")?;
+    let (path, rest) = rest.split_once("\", line ")?;
+    let row = rest.split(',').next()?;
+
+    Some(types::Location {
+        path: to_project_path(path, project_dir)?,
+        row: row.trim().parse().ok()?,
+        col: 0,
+    })
+}
+
+/// Checks if a line looks like "`ValueError: no greeting`"
+#[must_use]
+pub fn is_exception(line: &str) -> bool {
+    let Some((name, _)) = line.split_once(": ") else {
+        return false;
+    };
+    let class = name.rsplit('.').next().unwrap_or_default();
+
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+        && class.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Returns the full path for a file of the project
+///
+/// Files of installed packages, the standard library and frozen modules like "<frozen runpy>" are ignored
+#[must_use]
+pub fn to_project_path(path: &str, project_dir: &str) -> Option<String> {
+    if path.contains("site-packages") || path.starts_with('<') {
+        return None;
+    }
+    if path.starts_with('/') {
+        // "/tmp/project2" is not part of "/tmp/project"
+        return Path::new(path)
+            .starts_with(project_dir)
+            .then(|| path.to_string());
+    }
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    Some(format!("{project_dir}/{path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analyser::python::{analyse, to_project_path},
+        core::types,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_tracebacks() {
+        static LOG: &str = include_str!("../../tests/python_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error:
+                        "json.decoder.JSONDecodeError: Expecting value: line 1 column 1 (char 0)"
+                            .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/config.py".to_string(),
+                        row: 14,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "app.config.ConfigError: invalid config config.json".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/app/config.py".to_string(),
+                            row: 16,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/app/main.py".to_string(),
+                            row: 18,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/app/main.py".to_string(),
+                            row: 22,
+                            col: 0
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "SyntaxError: '(' was never closed".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/worker.py".to_string(),
+                        row: 3,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_ignore_sibling_directories() {
+        assert_eq!(
+            to_project_path("/tmp/project/app/main.py", "/tmp/project"),
+            Some("/tmp/project/app/main.py".to_string())
+        );
+        assert_eq!(
+            to_project_path("/tmp/project2/lib.py", "/tmp/project"),
+            None
+        );
+    }
+}
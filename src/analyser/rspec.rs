@@ -0,0 +1,209 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Rspec`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let mut descriptions: Vec<&str> = vec![];
+    let mut in_failures = false;
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            match *line {
+                "Failures:" => in_failures = true,
+                "Pending: (Failures listed here are expected and do not affect your suite's status)"
+                | "Failed examples:" => in_failures = false,
+                _ => {}
+            }
+            if in_failures {
+                if let Some(description) = parse_header(line) {
+                    descriptions.push(description);
+                    if let Some(error) = parse_failure(description, &lines[i + 1..], project_dir) {
+                        errors.push(error);
+                    }
+                }
+            }
+            if let Some(error) = parse_rerun(line, &descriptions, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses the header of a failure
+///
+/// Gets input like:
+/// "`  1) User#name returns the full name`"
+///         ------------------------------
+///         description
+fn parse_header(line: &str) -> Option<&str> {
+    let (number, description) = line.trim().split_once(") ")?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(description)
+}
+
+/// Parses the indented lines after the header of a failure
+///
+/// The message is the raised exception or the "Failure/Error:" line with the
+/// expected and actual value. The locations come from the backtrace.
+fn parse_failure(description: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let body = lines
+        .iter()
+        .take_while(|line| line.is_empty() || line.starts_with(char::is_whitespace))
+        .take_while(|line| parse_header(line).is_none())
+        .map(|line| line.trim())
+        .collect::<Vec<_>>();
+
+    let mut locations = body
+        .iter()
+        .filter_map(|line| parse_backtrace(line, project_dir))
+        .collect::<Vec<_>>();
+    locations.dedup();
+    if locations.is_empty() {
+        return None;
+    }
+
+    let exception = body.iter().position(|line| is_exception(line));
+    let message = if let Some(i) = exception {
+        format!("{} {}", body[i], body.get(i + 1)?)
+    } else {
+        let failure = body
+            .iter()
+            .find_map(|line| line.strip_prefix("Failure/Error: "))?;
+        let values = body
+            .iter()
+            .filter(|line| line.starts_with("expected: ") || line.starts_with("got: "))
+            .copied()
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            failure.to_string()
+        } else {
+            format!("{failure} ({})", values.join(", "))
+        }
+    };
+
+    Some(types::Message {
+        error: format!("{description}: {message}"),
+        locations,
+    })
+}
+
+/// Checks for a line like "`NoMethodError:`" that is followed by the exception message
+fn is_exception(line: &str) -> bool {
+    line.strip_suffix(':').is_some_and(|name| {
+        name.starts_with(|c: char| c.is_ascii_uppercase())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == ':')
+    })
+}
+
+/// Parses a line of the backtrace
+///
+/// Gets input like:
+/// "`# ./lib/cart.rb:12:in 'Cart#total'`"
+///     ------------- --
+///     path          row
+///
+/// Frames of installed gems are ignored
+fn parse_backtrace(line: &str, project_dir: &str) -> Option<types::Location> {
+    let location = line.strip_prefix("# ")?;
+    let (path, rest) = location.split_once(':')?;
+    let row = rest.split(':').next()?;
+    if path.contains("/gems/") {
+        return None;
+    }
+
+    Some(types::Location {
+        path: to_path(path, project_dir),
+        row: row.parse().ok()?,
+        col: 0,
+    })
+}
+
+/// Parses a rerun line that has no failure block
+///
+/// Gets input like:
+/// "`rspec ./spec/order_spec.rb:14 # Order is created in an after hook`"
+///         -------------------- --   --------------------------------
+///         path                 row  description
+fn parse_rerun(line: &str, descriptions: &[&str], project_dir: &str) -> Option<types::Message> {
+    let (location, description) = line.strip_prefix("rspec ")?.split_once(" # ")?;
+    if descriptions.contains(&description) {
+        return None;
+    }
+    let (path, row) = location.rsplit_once(':')?;
+
+    Some(types::Message {
+        error: description.to_string(),
+        locations: vec![types::Location {
+            path: to_path(path, project_dir),
+            row: row.parse().ok()?,
+            col: 0,
+        }],
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::rspec::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_failures() {
+        static LOG: &str = include_str!("../../tests/rspec_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "User#name returns the full name: expect(user.name).to eq(\"Jane Doe\") (expected: \"Jane Doe\", got: \"Jane\")".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/spec/user_spec.rb".to_string(),
+                        row: 8,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "Cart#total sums the prices: NoMethodError: undefined method 'price' for nil".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/lib/cart.rb".to_string(),
+                            row: 12,
+                            col: 0
+                        },
+                        types::Location {
+                            path: "/tmp/project/spec/cart_spec.rb".to_string(),
+                            row: 5,
+                            col: 0
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "Order is created in an after hook".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/spec/order_spec.rb".to_string(),
+                        row: 14,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+}
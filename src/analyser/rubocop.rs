@@ -0,0 +1,122 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Rubocop`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_offense(line, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses an offense
+///
+/// Gets input like:
+/// "`app/models/user.rb:7:5: W: Lint/UselessAssignment: Useless assignment to variable - y.`"
+///   ------------------ - -  -  ----------------------  ---------------------------------
+///   path               | col|  cop                     message
+///                      row  severity
+fn parse_offense(line: &str, project_dir: &str) -> Option<types::Message> {
+    let mut splits = line.splitn(4, ':');
+    let path = splits.next()?;
+    let row = splits.next()?.parse().ok()?;
+    let col = splits.next()?.parse().ok()?;
+    let rest = splits.next()?.trim_start();
+
+    let (letter, rest) = rest.split_once(": ")?;
+    let severity = severity(letter)?;
+    let rest = rest.strip_prefix("[Correctable] ").unwrap_or(rest);
+    let (cop_name, message) = rest.split_once(": ")?;
+
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    Some(types::Message {
+        error: format!("{severity}: {message} [{cop_name}]"),
+        locations: vec![types::Location { path, row, col }],
+    })
+}
+
+/// Maps the severity letter of rubocop to a severity level
+///
+/// Refactor and convention offenses are only notes
+fn severity(letter: &str) -> Option<&'static str> {
+    match letter {
+        "F" | "E" => Some("error"),
+        "W" => Some("warning"),
+        "C" | "R" => Some("note"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::rubocop::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_offenses() {
+        static LOG: &str = include_str!("../../tests/rubocop_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "note: Prefer single-quoted strings when you don't need string interpolation or special symbols. [Style/StringLiterals]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/models/user.rb".to_string(),
+                        row: 3,
+                        col: 12
+                    }]
+                },
+                types::Message {
+                    error: "warning: Useless assignment to variable - y. [Lint/UselessAssignment]"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/models/user.rb".to_string(),
+                        row: 7,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "note: Assignment Branch Condition size for total is too high. [<4, 18, 6> 19.39/17] [Metrics/AbcSize]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/cart.rb".to_string(),
+                        row: 4,
+                        col: 3
+                    }]
+                },
+                types::Message {
+                    error: "error: unexpected token kEND [Lint/Syntax]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/broken.rb".to_string(),
+                        row: 2,
+                        col: 1
+                    }]
+                },
+                types::Message {
+                    error: "error: An error occurred while Style/Documentation cop was inspecting lib/broken.rb. [Lint/Syntax]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/lib/broken.rb".to_string(),
+                        row: 1,
+                        col: 1
+                    }]
+                }
+            ]
+        );
+    }
+}
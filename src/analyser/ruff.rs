@@ -0,0 +1,167 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Ruff`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = &lines.len();
+
+    for i in 0..*line_len {
+        if let Some(line) = lines.get(i) {
+            if line.starts_with(char::is_whitespace) {
+                continue;
+            }
+            if let Some(error) = parse_concise(line, project_dir) {
+                errors.push(error);
+                continue;
+            }
+            if let Some(location_line) = lines.get(i + 1) {
+                if let Some(location) = location_line.trim_start().strip_prefix("--> ") {
+                    if let Some(location) = parse_location(location, project_dir) {
+                        errors.push(types::Message {
+                            error: parse_rule(line),
+                            locations: vec![location],
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parse message from the concise format
+///
+/// Gets line input like:
+/// "`app/main.py:1:8: F401 [*] os imported but unused`"
+///   -------------- ------------------------------
+///   `parse_location` `parse_rule`
+fn parse_concise(line: &str, project_dir: &str) -> Option<types::Message> {
+    let (location, rule) = line.match_indices(": ").find_map(|(i, _)| {
+        let location = parse_location(&line[..i], project_dir)?;
+        Some((location, &line[i + 2..]))
+    })?;
+
+    Some(types::Message {
+        error: parse_rule(rule),
+        locations: vec![location],
+    })
+}
+
+/// Parses location
+///
+/// Gets location input like:
+/// "`app/main.py:1:8`"
+///   ----------- - -
+///   path        | col
+///               row
+fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
+    let mut splits = location.rsplitn(3, ':');
+    let col = splits.next()?.parse().ok()?;
+    let row = splits.next()?.parse().ok()?;
+    let path = splits.next()?;
+
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    Some(types::Location { path, row, col })
+}
+
+/// Moves the rule code to the end of the message
+///
+/// Ruff prints no severity so none is added
+///
+/// Gets input like:
+/// "`F401 [*] os imported but unused`"
+///   ---- --- ----------------------
+///   code |   message
+///        fixable
+fn parse_rule(rule: &str) -> String {
+    if let Some((code, message)) = rule.split_once(' ') {
+        let is_code = code.starts_with(|c: char| c.is_ascii_uppercase())
+            && code.ends_with(|c: char| c.is_ascii_digit())
+            && code.chars().all(|c| c.is_ascii_alphanumeric());
+        if is_code {
+            let message = message.trim_start_matches("[*] ");
+            return format!("{message} [{code}]");
+        }
+    }
+
+    rule.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::ruff::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn concise() {
+        static LOG: &str = include_str!("../../tests/ruff_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "`os` imported but unused [F401]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/main.py".to_string(),
+                        row: 1,
+                        col: 8
+                    }]
+                },
+                types::Message {
+                    error: "Line too long (95 > 88) [E501]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/main.py".to_string(),
+                        row: 14,
+                        col: 89
+                    }]
+                },
+                types::Message {
+                    error: "SyntaxError: Expected ')', found newline".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/worker.py".to_string(),
+                        row: 3,
+                        col: 9
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn full() {
+        static LOG: &str = include_str!("../../tests/ruff_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "`os` imported but unused [F401]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/main.py".to_string(),
+                        row: 1,
+                        col: 8
+                    }]
+                },
+                types::Message {
+                    error: "Comparison to `None` should be `cond is None` [E711]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/app/models/user.py".to_string(),
+                        row: 21,
+                        col: 17
+                    }]
+                }
+            ]
+        );
+    }
+}
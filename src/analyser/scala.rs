@@ -0,0 +1,289 @@
+use std::path::Path;
+
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Scala`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            let (level, content) = split_level(line);
+            let body = lines[i + 1..]
+                .iter()
+                .map(|line| split_level(line))
+                .take_while(|(body_level, content)| {
+                    *body_level == level
+                        && (content.starts_with(char::is_whitespace) || is_numbered(content))
+                })
+                .map(|(_, content)| content)
+                .collect::<Vec<_>>();
+
+            if let Some(error) = parse_scala2(level, content, &body, project_dir) {
+                errors.push(error);
+            }
+            if let Some(error) = parse_scala3(level, content, &body, project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Splits the level that sbt puts in front of every line
+///
+/// Gets input like:
+/// "`[error] /tmp/project/src/main/scala/App.scala:12:18: type mismatch;`"
+///    -----  -------------------------------------------------------
+///    level  content
+fn split_level(line: &str) -> (Option<&str>, &str) {
+    for (prefix, level) in [
+        ("[error]", "error"),
+        ("[warn]", "warning"),
+        ("[info]", "info"),
+    ] {
+        if let Some(content) = line.strip_prefix(prefix) {
+            return (Some(level), content.strip_prefix(' ').unwrap_or(content));
+        }
+    }
+
+    (None, line)
+}
+
+/// Parses a diagnostic of Scala 2
+///
+/// Gets input like:
+/// "`/tmp/project/src/main/scala/App.scala:12:18: type mismatch;`"
+///   ------------------------------------- -- --  --------------
+///   path                                  |  col message
+///                                         row
+///
+/// The message continues until the source line above the caret.
+/// Without sbt the severity is part of the message like "`App.scala:5: error: not found`".
+fn parse_scala2(
+    level: Option<&str>,
+    content: &str,
+    body: &[&str],
+    project_dir: &str,
+) -> Option<types::Message> {
+    let mut splits = content.splitn(3, ':');
+    let path = splits.next()?;
+    let row = splits.next()?.parse().ok()?;
+    let rest = splits.next()?;
+    if !is_source(path) {
+        return None;
+    }
+    let (col, message) = match rest.split_once(':') {
+        Some((col, message)) if col.parse::<usize>().is_ok() => (col.parse().ok(), message),
+        _ => (None, rest),
+    };
+    let message = message.trim();
+
+    let (severity, message) = match level {
+        Some(level @ ("error" | "warning")) => (level, message),
+        Some(_) => return None,
+        None => message
+            .strip_prefix("error: ")
+            .map(|message| ("error", message))
+            .or_else(|| {
+                message
+                    .strip_prefix("warning: ")
+                    .map(|message| ("warning", message))
+            })?,
+    };
+
+    let caret = body.iter().position(|line| is_caret(line));
+    let details = caret.map_or(body, |caret| &body[..caret.saturating_sub(1)]);
+    let col = col
+        .or_else(|| caret.and_then(|caret| body[caret].find('^').map(|col| col + 1)))
+        .unwrap_or(0);
+
+    let mut message = vec![message];
+    message.extend(details.iter().map(|line| line.trim()));
+
+    Some(types::Message {
+        error: format!("{severity}: {}", message.join(" ")),
+        locations: vec![types::Location {
+            path: to_path(path, project_dir),
+            row,
+            col,
+        }],
+    })
+}
+
+/// Parses a diagnostic of Scala 3
+///
+/// Gets input like:
+/// "`-- [E007] Type Mismatch Error: /tmp/project/src/main/scala/App.scala:12:15`"
+///      ----   -------------------  ---------------------------------------
+///      code   title                location
+///
+/// The message is in the box below the caret
+fn parse_scala3(
+    level: Option<&str>,
+    content: &str,
+    body: &[&str],
+    project_dir: &str,
+) -> Option<types::Message> {
+    let header = content
+        .strip_prefix("-- ")?
+        .trim_end()
+        .trim_end_matches('-');
+    let (code, header) = match header.strip_prefix('[') {
+        Some(rest) => {
+            let (code, header) = rest.split_once("] ")?;
+            (Some(code), header)
+        }
+        None => (None, header),
+    };
+    let (title, location) = header.trim().rsplit_once(": ")?;
+
+    let mut splits = location.rsplitn(3, ':');
+    let col = splits.next()?.parse().ok()?;
+    let row = splits.next()?.parse().ok()?;
+    let path = splits.next()?;
+
+    let severity = match level {
+        Some(level @ ("error" | "warning")) => level,
+        Some(_) => return None,
+        None if title.contains("Warning") => "warning",
+        None => "error",
+    };
+
+    let details = body
+        .iter()
+        .filter_map(|line| line.split_once('|').map(|(_, text)| text))
+        .skip_while(|text| !is_caret(text))
+        .skip(1)
+        .map(str::trim)
+        .take_while(|text| !text.is_empty())
+        .collect::<Vec<_>>();
+    let message = if details.is_empty() {
+        title.to_string()
+    } else {
+        details.join(" ")
+    };
+    let error = code.map_or_else(
+        || format!("{severity}: {message}"),
+        |code| format!("{severity}: {message} [{code}]"),
+    );
+
+    Some(types::Message {
+        error,
+        locations: vec![types::Location {
+            path: to_path(path, project_dir),
+            row,
+            col,
+        }],
+    })
+}
+
+/// Checks for a source line of Scala 3 like "`12 |  val x: Int = "1"`"
+fn is_numbered(line: &str) -> bool {
+    line.trim_start_matches(|c: char| c.is_ascii_digit())
+        .starts_with(" |")
+}
+
+fn is_caret(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && line.chars().all(|c| c == '^')
+}
+
+fn is_source(path: &str) -> bool {
+    Path::new(path).extension().is_some_and(|extension| {
+        extension.eq_ignore_ascii_case("scala") || extension.eq_ignore_ascii_case("sc")
+    })
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::scala::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_scala2_errors() {
+        static LOG: &str = include_str!("../../tests/scala_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: type mismatch; found   : String(\"1\") required: Int"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main/scala/App.scala".to_string(),
+                        row: 12,
+                        col: 18
+                    }]
+                },
+                types::Message {
+                    error: "warning: private val y in object Util is never used".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main/scala/Util.scala".to_string(),
+                        row: 3,
+                        col: 15
+                    }]
+                },
+                types::Message {
+                    error: "error: not found: value conect".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main/scala/Db.scala".to_string(),
+                        row: 20,
+                        col: 5
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_scala3_errors() {
+        static LOG: &str = include_str!("../../tests/scala_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: Found:    (\"1\" : String) Required: Int [E007]".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main/scala/App.scala".to_string(),
+                        row: 12,
+                        col: 15
+                    }]
+                },
+                types::Message {
+                    error: "warning: unused explicit parameter".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/main/scala/Util.scala".to_string(),
+                        row: 4,
+                        col: 6
+                    }]
+                },
+                types::Message {
+                    error: "error: value lenght is not a member of String".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/Main.scala".to_string(),
+                        row: 5,
+                        col: 21
+                    }]
+                }
+            ]
+        );
+    }
+}
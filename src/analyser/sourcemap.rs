@@ -0,0 +1,169 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use crate::core::{types, uri};
+
+const BASE64: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Maps a location in generated javascript to its original source
+///
+/// The source map has to be next to the generated file like "dist/index.js.map".
+/// When there is no source map the location is returned unchanged.
+#[must_use]
+pub fn resolve(location: types::Location) -> types::Location {
+    original(&location).unwrap_or(location)
+}
+
+fn original(location: &types::Location) -> Option<types::Location> {
+    let content = fs::read_to_string(format!("{}.map", location.path)).ok()?;
+    let map: Value = serde_json::from_str(&content).ok()?;
+    let mappings = map.get("mappings")?.as_str()?;
+    let source_root = map
+        .get("sourceRoot")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    let (source, row, col) = lookup(
+        mappings,
+        location.row.checked_sub(1)?,
+        location.col.saturating_sub(1),
+    )?;
+    let source = map.get("sources")?.get(source)?.as_str()?;
+
+    let path = if source.starts_with("file://") {
+        PathBuf::from(uri::file_uri_to_path(source)?)
+    } else if source.contains("://") {
+        // Virtual sources like "webpack://" can not be opened
+        return None;
+    } else {
+        Path::new(&location.path)
+            .parent()?
+            .join(source_root)
+            .join(source)
+    };
+
+    Some(types::Location {
+        path: normalise(&path),
+        row: row + 1,
+        col: col + 1,
+    })
+}
+
+/// Finds the original source, row and col for a zero based generated row and col
+///
+/// Every segment of the mappings contains the generated col, the source index,
+/// the source row and the source col relative to the previous segment.
+fn lookup(mappings: &str, row: usize, col: usize) -> Option<(usize, usize, usize)> {
+    let col = i64::try_from(col).ok()?;
+    let (mut source, mut source_row, mut source_col) = (0, 0, 0);
+    let mut found = None;
+
+    for (line, segments) in mappings.split(';').enumerate().take(row + 1) {
+        let mut generated_col = 0;
+        for segment in segments.split(',').filter(|s| !s.is_empty()) {
+            let values = decode_vlq(segment)?;
+            generated_col = values.first()?.checked_add(generated_col)?;
+            if values.len() < 4 {
+                continue;
+            }
+            source = values[1].checked_add(source)?;
+            source_row = values[2].checked_add(source_row)?;
+            source_col = values[3].checked_add(source_col)?;
+
+            if line == row && (found.is_none() || generated_col <= col) {
+                found = Some((source, source_row, source_col));
+            }
+        }
+    }
+
+    let (source, source_row, source_col) = found?;
+    Some((
+        usize::try_from(source).ok()?,
+        usize::try_from(source_row).ok()?,
+        usize::try_from(source_col).ok()?,
+    ))
+}
+
+/// Decodes the base64 variable length quantities of a segment
+///
+/// Segments of a corrupt map with too many continuation digits are rejected
+fn decode_vlq(segment: &str) -> Option<Vec<i64>> {
+    let mut values = vec![];
+    let mut value = 0;
+    let mut shift = 0;
+
+    for c in segment.chars() {
+        let digit = i64::try_from(BASE64.find(c)?).ok()?;
+        // Five more bits do not fit into the value anymore
+        if shift >= 60 {
+            return None;
+        }
+        value = ((digit & 31) << shift).checked_add(value)?;
+        if digit & 32 == 0 {
+            // The lowest bit is the sign
+            values.push(if value & 1 == 1 {
+                -(value >> 1)
+            } else {
+                value >> 1
+            });
+            value = 0;
+            shift = 0;
+        } else {
+            shift += 5;
+        }
+    }
+    // The last digit still has the continuation bit
+    if shift != 0 {
+        return None;
+    }
+
+    Some(values)
+}
+
+/// Removes "." and ".." from a path without touching the file system
+#[must_use]
+pub fn normalise(path: &Path) -> String {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            component => out.push(component),
+        }
+    }
+
+    out.to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analyser::sourcemap::{decode_vlq, lookup};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_decode_vlq() {
+        assert_eq!(decode_vlq("AAEE"), Some(vec![0, 0, 2, 2]));
+        assert_eq!(decode_vlq("IACA"), Some(vec![4, 0, 1, 0]));
+        assert_eq!(decode_vlq("gBD"), Some(vec![16, -1]));
+    }
+
+    #[test]
+    fn should_reject_malformed_segments() {
+        assert_eq!(decode_vlq("/////////////"), None);
+        assert_eq!(decode_vlq("AA*A"), None);
+        assert_eq!(lookup("AAAA,/////////////A", 0, 0), None);
+    }
+
+    #[test]
+    fn should_lookup_mapping() {
+        assert_eq!(lookup("AAEE;IACA", 0, 0), Some((0, 2, 2)));
+        assert_eq!(lookup("AAEE;IACA", 1, 10), Some((0, 3, 2)));
+        assert_eq!(lookup("AAEE;IACA", 2, 0), None);
+    }
+}
@@ -0,0 +1,177 @@
+use std::path::Path;
+
+use crate::{analyser::clang, core::types};
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Swift`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            let Some((message, location)) = parse_line(line, project_dir) else {
+                continue;
+            };
+            // A note belongs to the error or warning before it
+            if message.starts_with("note:") {
+                if let Some(error) = errors.last_mut() {
+                    if is_source(&location.path) && !error.locations.contains(&location) {
+                        error.locations.push(location);
+                    }
+                }
+                continue;
+            }
+            errors.push(types::Message {
+                error: message.to_string(),
+                locations: vec![location],
+            });
+        }
+    }
+
+    // "swift build" prints the diagnostics of the compile and the emit-module step
+    let mut unique: Vec<types::Message> = vec![];
+    for error in errors {
+        if !unique.contains(&error) {
+            unique.push(error);
+        }
+    }
+
+    unique
+}
+
+/// Parses a diagnostic of the compiler or a failed `XCTest` assertion
+///
+/// Gets line input like:
+/// "`/tmp/project/Sources/App/main.swift:5:9: error: cannot find 'greet' in scope`"
+///   -----------------------------------      --------------------------------
+///   `clang::parse_location`                  message
+///
+/// `XCTest` has no col like "`/tmp/project/Tests/AppTests.swift:12: error: AppTests.testAdd : failed`"
+fn parse_line<'a>(line: &'a str, project_dir: &str) -> Option<(&'a str, types::Location)> {
+    if line.starts_with('<') {
+        return None;
+    }
+
+    let mut splits = line.splitn(3, ':');
+    let path = splits.next()?;
+    let row = splits.next()?;
+    let rest = splits.next()?.trim_start();
+    if is_severity(rest) {
+        let path = if path.starts_with('/') {
+            path.to_string()
+        } else {
+            format!("{project_dir}/{path}")
+        };
+        return Some((
+            rest,
+            types::Location {
+                path,
+                row: row.parse().ok()?,
+                col: 0,
+            },
+        ));
+    }
+
+    let location = clang::parse_location(line, project_dir)?;
+    let message = line.splitn(4, ':').nth(3)?.trim_start();
+    if !is_severity(message) {
+        return None;
+    }
+
+    Some((message, location))
+}
+
+fn is_severity(message: &str) -> bool {
+    ["error:", "warning:", "note:"]
+        .iter()
+        .any(|severity| message.starts_with(severity))
+}
+
+/// Notes can point into modules like "`Swift.Decodable:2:5`" that are no files
+fn is_source(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("swift"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::swift::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_build_errors() {
+        static LOG: &str = include_str!("../../tests/swift_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: cannot find 'greet' in scope".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/Sources/App/main.swift".to_string(),
+                        row: 5,
+                        col: 9
+                    }]
+                },
+                types::Message {
+                    error: "error: type 'User' does not conform to protocol 'Decodable'"
+                        .to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/Sources/App/User.swift".to_string(),
+                            row: 10,
+                            col: 8
+                        },
+                        types::Location {
+                            path: "/tmp/project/Sources/App/User.swift".to_string(),
+                            row: 12,
+                            col: 9
+                        }
+                    ]
+                },
+                types::Message {
+                    error: "warning: initialization of immutable value 'x' was never used; consider replacing with assignment to '_' or removing it".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/Sources/App/Util.swift".to_string(),
+                        row: 3,
+                        col: 9
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_failed_tests() {
+        static LOG: &str = include_str!("../../tests/swift_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: AppTests.testAdd : XCTAssertEqual failed: (\"3\") is not equal to (\"4\")".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/Tests/AppTests/AppTests.swift".to_string(),
+                        row: 12,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: -[AppTests.UserTests testName] : XCTAssertTrue failed"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/Tests/AppTests/UserTests.swift".to_string(),
+                        row: 20,
+                        col: 0
+                    }]
+                }
+            ]
+        );
+    }
+}
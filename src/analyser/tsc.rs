@@ -0,0 +1,210 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Tsc`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            let parsed = parse_line(line).or_else(|| parse_pretty_line(line));
+            if let Some((location, message)) = parsed {
+                if let Some(location) = parse_location(location, project_dir) {
+                    errors.push(types::Message {
+                        error: with_continuation(message, &lines[i + 1..]),
+                        locations: vec![location],
+                    });
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Splits a line of the default format
+///
+/// Gets line input like:
+/// "`src/a.ts(12,5): error TS2322: Type 'number' is not assignable to type 'string'.`"
+///   --------------  ------------------------------------------------------------
+///   location        message
+fn parse_line(line: &str) -> Option<(&str, &str)> {
+    let end = line.find("): error TS")?;
+
+    Some((&line[..=end], &line[end + 3..]))
+}
+
+/// Splits a line of the "--pretty" format
+///
+/// Gets line input like:
+/// "`src/a.ts:12:5 - error TS2322: Type 'number' is not assignable to type 'string'.`"
+///   ------------   ------------------------------------------------------------
+///   location       message
+fn parse_pretty_line(line: &str) -> Option<(&str, &str)> {
+    let (location, message) = line.split_once(" - ")?;
+    message
+        .starts_with("error TS")
+        .then_some((location, message))
+}
+
+/// Parses location
+///
+/// Gets location input like:
+/// "src/a.ts(12,5)" or "src/a.ts:12:5"
+///  --------  -- -
+///  path      |  col
+///            row
+///
+/// Paths can contain parens like "src/(app)/page.tsx:1:2"
+fn parse_location(location: &str, project_dir: &str) -> Option<types::Location> {
+    let (path, row, col) = parse_position(location).or_else(|| {
+        let mut splits = location.rsplitn(3, ':');
+        let col = splits.next()?;
+        let row = splits.next()?;
+        Some((splits.next()?, row, col))
+    })?;
+
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{project_dir}/{path}")
+    };
+
+    Some(types::Location {
+        path,
+        row: row.parse().ok()?,
+        col: col.parse().ok()?,
+    })
+}
+
+/// Splits a position like "src/a.ts(12,5)"
+fn parse_position(location: &str) -> Option<(&str, &str, &str)> {
+    let (path, position) = location.strip_suffix(')')?.rsplit_once('(')?;
+    let (row, col) = position.split_once(',')?;
+    let is_number = |text: &str| !text.is_empty() && text.chars().all(|c| c.is_ascii_digit());
+
+    (is_number(row) && is_number(col)).then_some((path, row, col))
+}
+
+/// Adds the indented lines that explain the error to the message
+fn with_continuation(message: &str, lines: &[&str]) -> String {
+    let mut message = message.to_string();
+    for line in lines {
+        let Some(continuation) = line.strip_prefix("  ") else {
+            break;
+        };
+        // A related location like "  src/types.ts:3:3" follows the code frame
+        if continuation.starts_with(' ') || continuation.is_empty() {
+            break;
+        }
+        message.push(' ');
+        message.push_str(continuation.trim());
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::tsc::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn error() {
+        static LOG: &str = include_str!("../../tests/tsc_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error TS2322: Type 'number' is not assignable to type 'string'."
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/a.ts".to_string(),
+                        row: 12,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "error TS2345: Argument of type '{ a: number; }' is not assignable to parameter of type 'Foo'. Property 'b' is missing in type '{ a: number; }' but required in type 'Foo'.".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/components/List.vue".to_string(),
+                        row: 20,
+                        col: 3
+                    }]
+                },
+                types::Message {
+                    error: "error TS6133: 'x' is declared but its value is never read."
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/b.ts".to_string(),
+                        row: 1,
+                        col: 1
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        static LOG: &str = include_str!("../../tests/tsc_2.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error TS2322: Type 'number' is not assignable to type 'string'."
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/a.ts".to_string(),
+                        row: 12,
+                        col: 5
+                    }]
+                },
+                types::Message {
+                    error: "error TS2345: Argument of type '{ a: number; }' is not assignable to parameter of type 'Foo'. Property 'b' is missing in type '{ a: number; }' but required in type 'Foo'.".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/components/List.vue".to_string(),
+                        row: 20,
+                        col: 3
+                    }]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn paths_with_parens() {
+        static LOG: &str = include_str!("../../tests/tsc_3.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error TS2304: Cannot find name 'props'.".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/(app)/page.tsx".to_string(),
+                        row: 1,
+                        col: 2
+                    }]
+                },
+                types::Message {
+                    error: "error TS2322: Type 'number' is not assignable to type 'string'."
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/(app)/layout.tsx".to_string(),
+                        row: 4,
+                        col: 7
+                    }]
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{analyser::node::parse_location, core::types};
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Vitest`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(title) = line.trim().strip_prefix("FAIL ") {
+                if let Some(error) = parse_failure(title.trim(), &lines[i + 1..], project_dir) {
+                    errors.push(error);
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses the lines after a failed test
+///
+/// Gets input like:
+/// "`FAIL  src/sum.test.ts > math > adds numbers`"
+///                          -------------------- test
+/// "`AssertionError: expected 3 to be 4 // Object.is equality`"
+///   -------------------------------------------------------- message
+/// "`❯ read src/read.ts:4:12`"
+///          --------------- `parse_location`
+fn parse_failure(title: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let test = title.split_once(" > ").map_or(title, |(_, test)| test);
+    let mut message = None;
+    let mut locations = vec![];
+
+    for line in lines {
+        let line = line.trim();
+        if line.starts_with('⎯') || line.starts_with("FAIL ") || line.starts_with("Test Files") {
+            break;
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(frame) = line.strip_prefix("❯ ") {
+            let location = frame.rsplit(' ').next().unwrap_or(frame);
+            if let Some(location) = parse_location(location, project_dir) {
+                locations.push(location);
+            }
+        } else if message.is_none() {
+            message = Some(line);
+        }
+    }
+
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(types::Message {
+        error: format!("{test}: {}", message?),
+        locations,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::vitest::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_failed_tests() {
+        static LOG: &str = include_str!("../../tests/vitest_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "math > adds numbers: AssertionError: expected 3 to be 4 // Object.is equality".to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/sum.test.ts".to_string(),
+                        row: 12,
+                        col: 23
+                    }]
+                },
+                types::Message {
+                    error: "math > reads value: TypeError: Cannot read properties of undefined (reading 'x')".to_string(),
+                    locations: vec![
+                        types::Location {
+                            path: "/tmp/project/src/read.ts".to_string(),
+                            row: 4,
+                            col: 12
+                        },
+                        types::Location {
+                            path: "/tmp/project/src/sum.test.ts".to_string(),
+                            row: 18,
+                            col: 5
+                        }
+                    ]
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::core::types;
+
+/// Contains the analyser code for the [`crate::config::ParserKind::Webpack`]
+#[must_use]
+pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
+    let mut errors: Vec<types::Message> = vec![];
+    let lines = log.lines().collect::<Vec<&str>>();
+    let lines = lines.as_slice();
+    let line_len = lines.len();
+
+    for i in 0..line_len {
+        if let Some(line) = lines.get(i) {
+            if let Some(error) = parse_block(line, &lines[i + 1..], project_dir) {
+                errors.push(error);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Parses an "ERROR in" or "WARNING in" block
+///
+/// Gets input like:
+/// "`ERROR in ./src/index.ts 5:2-10`"
+///   -----    ------------------
+///   severity module
+///
+/// The message is the first line of the block. The ts-loader prints its own
+/// "`[tsl] ERROR in /tmp/project/src/math.ts(3,7)`" line that is more precise.
+fn parse_block(line: &str, lines: &[&str], project_dir: &str) -> Option<types::Message> {
+    let (severity, module) = parse_header(line)?;
+    let block = lines
+        .iter()
+        .take_while(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+
+    if let Some(i) = block.iter().position(|line| parse_tsl(line).is_some()) {
+        let (path, row, col) = parse_tsl(block[i])?;
+        let message = block.get(i + 1)?.trim();
+        return Some(types::Message {
+            error: format!("{severity}: {message}"),
+            locations: vec![types::Location {
+                path: to_path(path, project_dir),
+                row,
+                col,
+            }],
+        });
+    }
+
+    Some(types::Message {
+        error: format!("{severity}: {}", block.first()?.trim()),
+        locations: vec![parse_module(module, project_dir)?],
+    })
+}
+
+fn parse_header(line: &str) -> Option<(&str, &str)> {
+    if let Some(module) = line.strip_prefix("ERROR in ") {
+        return Some(("error", module.trim()));
+    }
+    line.strip_prefix("WARNING in ")
+        .map(|module| ("warning", module.trim()))
+}
+
+/// Parses the module of a block
+///
+/// Gets input like:
+/// "`./src/app.tsx + 3 modules 12:4-17`" or "`./src/form.tsx:14:9`"
+///   -------------             -- -          -------------- -- -
+///   path                      |  col        path           |  col
+///                             row                          row
+///
+/// Chunks like "main" have no file and are ignored
+fn parse_module(module: &str, project_dir: &str) -> Option<types::Location> {
+    let mut words = module.split_whitespace();
+    let first = words.next()?;
+    let last = words.last();
+
+    let (path, position) = last.map_or_else(
+        || {
+            first
+                .split_once(':')
+                .map_or((first, None), |(path, position)| (path, Some(position)))
+        },
+        |position| (first, Some(position)),
+    );
+    if !path.contains(['/', '\\']) {
+        return None;
+    }
+
+    // The position can be a range like "5:2-10" or "5:2-6:3"
+    let (row, col) = position
+        .and_then(|position| position.split('-').next())
+        .and_then(|position| position.split_once(':'))
+        .unwrap_or(("0", "0"));
+
+    Some(types::Location {
+        path: to_path(path, project_dir),
+        row: row.parse().ok()?,
+        col: col.parse().ok()?,
+    })
+}
+
+/// Parses the location of ts-loader
+///
+/// Gets input like:
+/// "`[tsl] ERROR in /tmp/project/src/math.ts(3,7)`"
+///                  ------------------------ - -
+///                  path                     | col
+///                                           row
+fn parse_tsl(line: &str) -> Option<(&str, usize, usize)> {
+    let line = line.trim().strip_prefix("[tsl] ")?;
+    let (_, location) = line.split_once(" in ")?;
+    let (path, position) = location.rsplit_once('(')?;
+    let (row, col) = position.strip_suffix(')')?.split_once(',')?;
+
+    Some((path, row.parse().ok()?, col.parse().ok()?))
+}
+
+fn to_path(path: &str, project_dir: &str) -> String {
+    if path.starts_with('/') {
+        return path.to_string();
+    }
+    let path = path.strip_prefix("./").unwrap_or(path);
+
+    format!("{project_dir}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyser::webpack::analyse, core::types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_find_errors() {
+        static LOG: &str = include_str!("../../tests/webpack_1.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![
+                types::Message {
+                    error: "error: Module not found: Error: Can't resolve './button' in '/tmp/project/src'"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/index.ts".to_string(),
+                        row: 5,
+                        col: 2
+                    }]
+                },
+                types::Message {
+                    error: "warning: export 'Header' (imported as 'Header') was not found in './header' (possible exports: Footer)"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/app.tsx".to_string(),
+                        row: 12,
+                        col: 4
+                    }]
+                },
+                types::Message {
+                    error: "error: Module build failed (from ./node_modules/css-loader/dist/cjs.js):"
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/styles.css".to_string(),
+                        row: 0,
+                        col: 0
+                    }]
+                },
+                types::Message {
+                    error: "error: TS2322: Type 'string' is not assignable to type 'number'."
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/math.ts".to_string(),
+                        row: 3,
+                        col: 7
+                    }]
+                },
+                types::Message {
+                    error: "error: TS2339: Property 'valu' does not exist on type 'Props'."
+                        .to_string(),
+                    locations: vec![types::Location {
+                        path: "/tmp/project/src/form.tsx".to_string(),
+                        row: 14,
+                        col: 9
+                    }]
+                }
+            ]
+        );
+    }
+}
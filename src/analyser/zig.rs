@@ -1,4 +1,4 @@
-use crate::core::types;
+use crate::{analyser::linker, core::types};
 
 const DELIMITERS: [&str; 2] = [": error: ", ": note: "];
 
@@ -12,6 +12,7 @@ pub fn analyse(log: &str, project_dir: &str) -> Vec<types::Message> {
 
     for i in 0..*line_len {
         if let Some(line) = lines.get(i) {
+            errors.extend(linker::parse(line, &lines[i + 1..], project_dir));
             for delimiter in DELIMITERS {
                 if line.contains(delimiter) {
                     if let Some(error) = parse_line(delimiter, line, project_dir) {
@@ -117,4 +118,22 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn linker() {
+        static LOG: &str = include_str!("../../tests/zig_3.log");
+        let result = analyse(LOG, "/tmp/project");
+
+        assert_eq!(
+            result,
+            vec![types::Message {
+                error: "error: undefined symbol: render".to_string(),
+                locations: vec![types::Location {
+                    path: "/tmp/project/src/main.zig".to_string(),
+                    row: 9,
+                    col: 0
+                }]
+            }]
+        );
+    }
 }
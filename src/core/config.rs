@@ -1,12 +1,12 @@
 use clap::Parser;
-use std::str::FromStr;
+use std::{fs, path::Path, str::FromStr};
 
 /// A build log analysis tool
 #[derive(Parser, Debug, Clone)]
 #[clap(author, version, about, long_about = None)]
 pub struct Args {
     /// The type of log input "Cargo", "Maven" or "`KarmaJasmine`"
-    #[clap(short, long, value_enum, required_if_eq_any([("input", "stdin"), ("input", "tmux"), ("input", "wezterm")]))]
+    #[clap(short, long, value_enum, required_if_eq_any([("input", "stdin"), ("input", "tmux"), ("input", "wezterm"), ("input", "socket")]))]
     pub parser: Option<ParserKind>,
 
     /// The input method that should be used to collect the log.
@@ -18,21 +18,45 @@ pub struct Args {
     pub command: Option<String>,
 
     /// Additional reference to selected input
-    #[clap(short, long, default_value = None, required_if_eq_any([("input", "tmux"), ("input", "wezterm"), ("input", "file")]))]
+    #[clap(short, long, default_value = None, required_if_eq_any([("input", "tmux"), ("input", "wezterm"), ("input", "file"), ("input", "socket")]))]
     pub target: Option<String>,
 
+    /// Keep reading the file target when it grows like "tail -f"
+    #[clap(short, long, value_enum, default_value = "once", default_missing_value = "tail", num_args = 0..=1)]
+    pub follow: FollowMode,
+
     /// Your shell PS1 in order to split logs for tmux
     #[clap(short, long, default_value = None, required_if_eq_any([("input", "tmux"), ("input", "wezterm")]))]
     pub splitby: Option<String>,
 
+    /// Which build of a tmux or wezterm pane should be analysed
+    #[clap(long, value_enum, default_value = "last-failed")]
+    pub build: BuildSelection,
+
+    /// Analyse the nth most recent build of a pane, 1 is the latest
+    #[clap(long, default_value = None, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub nth: Option<usize>,
+
+    /// Only consider builds of a pane whose command line matches this regex
+    #[clap(long, default_value = None)]
+    pub build_filter: Option<String>,
+
     /// The java package of your java project
     #[clap(long, default_value = None, required_if_eq("parser", "java"))]
     pub package: Option<String>,
 
+    /// Map javascript locations through adjacent source maps for the node parser
+    #[clap(long, value_enum, default_value = "ignore", default_missing_value = "map", num_args = 0..=1)]
+    pub source_maps: SourceMaps,
+
     /// The output method
     #[clap(short, long, default_value = "file", num_args = 0..)]
     pub output: Vec<OutputKind>,
 
+    /// What a testcase of the junit output is
+    #[clap(long, value_enum, default_value = "file")]
+    pub junit_group: JunitGroup,
+
     /// Watch files to rerun
     #[clap(short, long, num_args = 0..,  default_missing_value = "./src")]
     pub watch: Option<String>,
@@ -53,9 +77,15 @@ impl Default for Args {
             input: None,
             command: None,
             target: None,
+            follow: FollowMode::default(),
             splitby: None,
+            build: BuildSelection::default(),
+            nth: None,
+            build_filter: None,
             package: None,
+            source_maps: SourceMaps::default(),
             output: vec![],
+            junit_group: JunitGroup::default(),
             watch: None,
             clear: true,
             print_input: true,
@@ -64,7 +94,7 @@ impl Default for Args {
 }
 
 /// Pecifies witch parser to use
-#[derive(clap::ValueEnum, Clone, Debug)]
+#[derive(clap::ValueEnum, Clone, Debug, PartialEq, Eq)]
 pub enum ParserKind {
     /// The parser for Alire
     Alire,
@@ -80,24 +110,70 @@ pub enum ParserKind {
     Gcc,
     /// The parser for gradle
     Gradle,
+    /// The parser for jest
+    Jest,
+    /// The parser for `JUnit` XML test reports
+    Junit,
     /// The parser for Karma with Jasmine
     KarmaJasmine,
+    /// The parser for kotlinc
+    Kotlin,
+    /// The parser for ld, lld and mold
+    Linker,
     /// The parser for maven
     Maven,
     /// The parser for nix
     Nix,
+    /// The parser for node, deno and bun stack traces
+    Node,
     /// The parser for odin
     Odin,
+    /// The parser for dotnet build and dotnet test
+    Dotnet,
     /// The parser for dune
     Dune,
+    /// The parser for elixir with mix and `ExUnit`
+    Elixir,
+    /// The parser for esbuild and vite
+    Esbuild,
     /// The parser for eslint
     Eslint,
+    /// The parser for ghc, cabal and stack
+    Ghc,
     /// The parser for go
     Go,
     /// The parser for java
     Java,
+    /// The parser for mypy
+    Mypy,
+    /// The parser for phpstan
+    Phpstan,
+    /// The parser for phpunit
+    Phpunit,
+    /// The parser for pyright
+    Pyright,
+    /// The parser for pytest
+    Pytest,
+    /// The parser for python tracebacks
+    Python,
+    /// The parser for rspec
+    Rspec,
+    /// The parser for rubocop
+    Rubocop,
+    /// The parser for ruff
+    Ruff,
+    /// The parser for scalac and sbt
+    Scala,
+    /// The parser for swift build and swift test
+    Swift,
+    /// The parser for the typescript compiler
+    Tsc,
     /// The parser for v
     V,
+    /// The parser for vitest
+    Vitest,
+    /// The parser for webpack
+    Webpack,
     /// The parser for zig
     Zig,
 }
@@ -110,21 +186,117 @@ impl FromStr for ParserKind {
             "clang" => Ok(Self::Clang),
             "alr" => Ok(Self::Alire),
             "cargo" | "typos" => Ok(Self::Cargo),
+            "dotnet" | "msbuild" => Ok(Self::Dotnet),
             "dune" => Ok(Self::Dune),
-            "go" => Ok(Self::Go),
+            "elixir" | "mix" => Ok(Self::Elixir),
+            "esbuild" | "vite" => Ok(Self::Esbuild),
+            "ghc" | "cabal" | "stack" => Ok(Self::Ghc),
+            "go" | "golangci-lint" | "staticcheck" => Ok(Self::Go),
             "gradle" | "./gradlew" => Ok(Self::Gradle),
-            "gcc" => Ok(Self::Gcc),
+            "gcc" | "make" | "cmake" | "ninja" => Ok(Self::Gcc),
             "java" => Ok(Self::Java),
+            "jest" => Ok(Self::Jest),
+            "junit" => Ok(Self::Junit),
             "karma-jasmine" => Ok(Self::KarmaJasmine),
+            "kotlinc" | "kotlin" => Ok(Self::Kotlin),
+            "ld" | "ld.lld" | "lld" | "mold" => Ok(Self::Linker),
             "maven" | "mvn" => Ok(Self::Maven),
+            "mypy" | "dmypy" => Ok(Self::Mypy),
+            "node" | "deno" | "bun" | "ts-node" | "tsx" => Ok(Self::Node),
             "odin" => Ok(Self::Odin),
+            "phpstan" => Ok(Self::Phpstan),
+            "phpunit" => Ok(Self::Phpunit),
+            "pyright" | "basedpyright" => Ok(Self::Pyright),
+            "pytest" | "py.test" => Ok(Self::Pytest),
+            "python" | "python3" => Ok(Self::Python),
+            "rspec" => Ok(Self::Rspec),
+            "rubocop" => Ok(Self::Rubocop),
+            "ruff" => Ok(Self::Ruff),
+            "sbt" | "scalac" | "scala" => Ok(Self::Scala),
+            "swift" | "swiftc" => Ok(Self::Swift),
+            "tsc" | "vue-tsc" => Ok(Self::Tsc),
             "v" => Ok(Self::V),
+            "vitest" => Ok(Self::Vitest),
+            "webpack" | "webpack-cli" => Ok(Self::Webpack),
             "zig" => Ok(Self::Zig),
             &_ => Err(()),
         }
     }
 }
 
+impl ParserKind {
+    /// Guesses the parser from the command that produces the log
+    ///
+    /// Scripts of npm, yarn and pnpm are looked up in the "package.json" of the current directory
+    #[must_use]
+    pub fn guess(command: &str) -> Option<Self> {
+        Self::guess_in(command, Path::new("."))
+    }
+
+    fn guess_in(command: &str, project_dir: &Path) -> Option<Self> {
+        let mut words = command.split_whitespace();
+        let program = words.next()?;
+
+        // "npm run build", "yarn test" or "pnpm test"
+        if matches!(program, "npm" | "yarn" | "pnpm") {
+            let script = words.find(|word| !word.starts_with('-') && *word != "run")?;
+            // "yarn jest --ci" runs a binary when there is no such script
+            let command = read_script(project_dir, script).unwrap_or_else(|| {
+                std::iter::once(script)
+                    .chain(words)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            return Self::guess_program(&command);
+        }
+
+        Self::guess_program(command)
+    }
+
+    fn guess_program(command: &str) -> Option<Self> {
+        let mut words = command.split_whitespace();
+        let mut program = words.next()?;
+
+        // "npx tsc" or "php vendor/bin/phpunit"
+        if matches!(program, "npx" | "pnpx" | "bunx" | "php") {
+            program = words.find(|word| !word.starts_with('-'))?;
+        }
+
+        // "bundle exec rspec"
+        if program == "bundle" && words.next() == Some("exec") {
+            program = words.find(|word| !word.starts_with('-'))?;
+        }
+
+        if program.starts_with("python") {
+            // "python -m pytest"
+            if words.next() == Some("-m") {
+                if let Some(module) = words.next().and_then(|m| Self::from_str(m).ok()) {
+                    return Some(module);
+                }
+            }
+            return Some(Self::Python);
+        }
+
+        // "vendor/bin/phpstan"
+        let name = program.rsplit('/').next().unwrap_or(program);
+        Self::from_str(program)
+            .or_else(|()| Self::from_str(name))
+            .ok()
+    }
+}
+
+/// Reads the command of a script from the "package.json" in the directory
+fn read_script(project_dir: &Path, name: &str) -> Option<String> {
+    let content = fs::read_to_string(project_dir.join("package.json")).ok()?;
+    let package: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    package
+        .get("scripts")?
+        .get(name)?
+        .as_str()
+        .map(str::to_string)
+}
+
 /// Specifies the input of the parser
 #[derive(clap::ValueEnum, Clone, Debug, Default, PartialEq, Eq)]
 pub enum InputKind {
@@ -137,10 +309,22 @@ pub enum InputKind {
     Wezterm,
     /// Take input from a command that logana will execute
     Command,
-    /// Take input from a specified file
+    /// Take input that is pushed to a unix socket or fifo, one build per connection
+    Socket,
+    /// Take input from a specified file, directory or glob. ".gz", ".zst" and ".zip" files are decompressed
     File,
 }
 
+/// Specifies which build of a pane is picked
+#[derive(clap::ValueEnum, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BuildSelection {
+    /// The most recent build that has errors
+    #[default]
+    LastFailed,
+    /// The most recent build even if it succeeded
+    Latest,
+}
+
 /// Specifies the output type for an analysis
 #[derive(clap::ValueEnum, Clone, Debug, Default, PartialEq, Eq)]
 pub enum OutputKind {
@@ -149,27 +333,131 @@ pub enum OutputKind {
     File,
     /// Will write it to stdout
     Stdout,
+    /// Will write a .logana-junit.xml for CI test dashboards
+    Junit,
+}
+
+/// Specifies what a testcase of the junit output is
+#[derive(clap::ValueEnum, Clone, Debug, Default, PartialEq, Eq)]
+pub enum JunitGroup {
+    /// One testcase per file with all its messages
+    #[default]
+    File,
+    /// One testcase per message
+    Message,
+}
+
+/// Specifies how a file target is read
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FollowMode {
+    /// Read the file once
+    #[default]
+    Once,
+    /// Keep reading the file when it grows like "tail -f"
+    Tail,
+}
+
+/// Specifies if javascript locations are mapped through source maps
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SourceMaps {
+    /// Keep the locations of the stack trace
+    #[default]
+    Ignore,
+    /// Map the locations through adjacent source maps
+    Map,
 }
 
 impl Args {
     /// Provides fallbacks for cli arguments
     pub fn validate(args: &mut Self) {
         if args.parser.is_none() {
-            let Some(command) = &args.command else {
-                return;
-            };
-
-            if command.contains(' ') {
-                if let Some((first_word, _)) = command.split_once(' ') {
-                    args.parser = ParserKind::from_str(first_word).ok();
-                }
-            } else {
-                args.parser = ParserKind::from_str(command).ok();
+            if let Some(command) = &args.command {
+                args.parser = ParserKind::guess(command);
             }
         }
 
+        // "--input file --target TEST-AppTest.xml"
+        let is_xml = args.target.as_ref().is_some_and(|target| {
+            Path::new(target)
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"))
+        });
+        if args.parser.is_none() && is_xml {
+            args.parser = Some(ParserKind::Junit);
+        }
+
         if args.input.is_none() && args.command.is_some() {
             args.input = Some(InputKind::Command);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::config::{Args, FollowMode, ParserKind, SourceMaps};
+    use clap::Parser;
+    use pretty_assertions::assert_eq;
+    use std::path::Path;
+
+    #[test]
+    fn should_parse_flags_as_modes() {
+        let args = Args::try_parse_from(["logana", "--follow", "--source-maps"]).expect("args");
+        assert_eq!(args.follow, FollowMode::Tail);
+        assert_eq!(args.source_maps, SourceMaps::Map);
+
+        let args = Args::try_parse_from(["logana", "-f", "-p", "node"]).expect("args");
+        assert_eq!(args.follow, FollowMode::Tail);
+        assert_eq!(args.source_maps, SourceMaps::Ignore);
+
+        let args = Args::try_parse_from(["logana"]).expect("args");
+        assert_eq!(args.follow, FollowMode::Once);
+        assert_eq!(args.source_maps, SourceMaps::Ignore);
+    }
+
+    #[test]
+    fn should_guess_wrapped_programs() {
+        assert_eq!(ParserKind::guess("npx tsc --noEmit"), Some(ParserKind::Tsc));
+        assert_eq!(
+            ParserKind::guess("npx --yes vitest run"),
+            Some(ParserKind::Vitest)
+        );
+        assert_eq!(
+            ParserKind::guess("bundle exec rspec"),
+            Some(ParserKind::Rspec)
+        );
+        assert_eq!(
+            ParserKind::guess("python -m pytest -x"),
+            Some(ParserKind::Pytest)
+        );
+        assert_eq!(
+            ParserKind::guess("python3 -m mypy src"),
+            Some(ParserKind::Mypy)
+        );
+        assert_eq!(
+            ParserKind::guess("python main.py"),
+            Some(ParserKind::Python)
+        );
+        assert_eq!(
+            ParserKind::guess("vendor/bin/phpstan analyse"),
+            Some(ParserKind::Phpstan)
+        );
+        assert_eq!(
+            ParserKind::guess("php vendor/bin/phpunit"),
+            Some(ParserKind::Phpunit)
+        );
+        assert_eq!(ParserKind::guess("unknown --flag"), None);
+    }
+
+    #[test]
+    fn should_guess_package_scripts() {
+        let project_dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/npm_project"));
+        let guess = |command| ParserKind::guess_in(command, project_dir);
+
+        assert_eq!(guess("npm run build"), Some(ParserKind::Tsc));
+        assert_eq!(guess("npm test"), Some(ParserKind::Jest));
+        assert_eq!(guess("yarn bundle"), Some(ParserKind::Webpack));
+        assert_eq!(guess("pnpm --silent run test"), Some(ParserKind::Jest));
+        assert_eq!(guess("yarn vitest --run"), Some(ParserKind::Vitest));
+        assert_eq!(guess("npm ci"), None);
+    }
+}
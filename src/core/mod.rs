@@ -7,3 +7,6 @@ pub mod output;
 /// The shared type definitions for analyser
 #[warn(missing_docs)]
 pub mod types;
+/// Helpers for file uris
+#[warn(missing_docs)]
+pub mod uri;
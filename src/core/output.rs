@@ -1,7 +1,7 @@
 use std::{fs::File, io::prelude::*, path::Path};
 
 use super::{
-    config::{Args, OutputKind},
+    config::{Args, JunitGroup, OutputKind},
     types,
 };
 
@@ -18,6 +18,7 @@ pub fn produce(args: &Args, report: &types::AnalyseReport) {
                     .fold(String::new(), |a, b| a + &b + "\n")
             ),
             OutputKind::File => file(report),
+            OutputKind::Junit => junit(args, report),
         }
     }
 }
@@ -35,3 +36,270 @@ fn file(content: &types::AnalyseReport) {
     let mut file = File::create(path).expect("Create own config file");
     write!(file, "{content}").expect("Write file");
 }
+
+/// Saves the report as junit xml
+fn junit(args: &Args, report: &types::AnalyseReport) {
+    let path = Path::new(".logana-junit.xml");
+
+    let content = junit_xml(report, &args.junit_group);
+
+    let mut file = File::create(path).expect("Create junit file");
+    write!(file, "{content}").expect("Write file");
+}
+
+/// Converts a report into junit xml
+///
+/// Every file or message is a failed testcase. Messages without a location are named after the
+/// source of the report. A report without errors is one passed testcase.
+fn junit_xml(report: &types::AnalyseReport, group: &JunitGroup) -> String {
+    let mut testcases = vec![];
+    match group {
+        JunitGroup::File => {
+            let mut files: Vec<(Option<&str>, Vec<&types::Message>)> = vec![];
+            for message in &report.errors {
+                let path = message
+                    .locations
+                    .first()
+                    .map(|location| location.path.as_str());
+                match files.iter_mut().find(|(found, _)| *found == path) {
+                    Some((_, messages)) => messages.push(message),
+                    None => files.push((path, vec![message])),
+                }
+            }
+            for (path, messages) in files {
+                let failure = match messages.as_slice() {
+                    [message] => message.error.clone(),
+                    messages => format!("{} problems", messages.len()),
+                };
+                let details = messages
+                    .iter()
+                    .map(|message| describe(report, message))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let file = path.map(|path| relative(report, path));
+                let name = file.as_deref().unwrap_or(&report.source);
+                testcases.push(testcase(name, file.as_deref(), &failure, &details));
+            }
+        }
+        JunitGroup::Message => {
+            for message in &report.errors {
+                let file = message
+                    .locations
+                    .first()
+                    .map(|location| (relative(report, &location.path), location));
+                let name = file.as_ref().map_or_else(
+                    || report.source.clone(),
+                    |(file, location)| format!("{file}:{}:{}", location.row, location.col),
+                );
+                let file = file.as_ref().map(|(file, _)| file.as_str());
+                let details = describe(report, message);
+                testcases.push(testcase(&name, file, &message.error, &details));
+            }
+        }
+    }
+
+    let failures = testcases.len();
+    if testcases.is_empty() {
+        testcases.push(format!(
+            "    <testcase name=\"{}\" classname=\"logana\"/>\n",
+            escape(&report.source)
+        ));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"logana\" tests=\"{tests}\" failures=\"{failures}\">\n  \
+         <testsuite name=\"{source}\" tests=\"{tests}\" failures=\"{failures}\" timestamp=\"{date}\">\n\
+         {testcases}  </testsuite>\n\
+         </testsuites>\n",
+        tests = testcases.len(),
+        source = escape(&report.source),
+        date = report.date.format("%Y-%m-%dT%H:%M:%S"),
+        testcases = testcases.concat(),
+    )
+}
+
+fn testcase(name: &str, file: Option<&str>, failure: &str, details: &str) -> String {
+    let file = file.map_or_else(String::new, |file| format!(" file=\"{}\"", escape(file)));
+    format!(
+        "    <testcase name=\"{name}\" classname=\"logana\"{file}>\n      \
+         <failure message=\"{failure}\" type=\"logana\">{details}</failure>\n    \
+         </testcase>\n",
+        name = escape(name),
+        failure = escape(failure),
+        details = escape(details),
+    )
+}
+
+/// Describes a message like "`src/main.rs:4:2: unused variable`"
+fn describe(report: &types::AnalyseReport, message: &types::Message) -> String {
+    let Some(location) = message.locations.first() else {
+        return message.error.clone();
+    };
+
+    format!(
+        "{}:{}:{}: {}",
+        relative(report, &location.path),
+        location.row,
+        location.col,
+        message.error
+    )
+}
+
+/// CI dashboards show paths relative to the repository
+fn relative(report: &types::AnalyseReport, path: &str) -> String {
+    // "/tmp/project2/a.rs" is not part of "/tmp/project"
+    Path::new(path).strip_prefix(&report.project).map_or_else(
+        |_| path.to_string(),
+        |path| path.to_string_lossy().to_string(),
+    )
+}
+
+/// Escapes text for xml and removes characters that are not allowed like "\x1B"
+fn escape(text: &str) -> String {
+    strip_ansi(text)
+        .chars()
+        .filter(|c| {
+            matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}')
+                || *c >= '\u{10000}'
+        })
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Removes ansi sequences like "\x1B[0m" so that no "[0m" is left after escaping
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1B' {
+            out.push(c);
+            continue;
+        }
+        if chars.clone().next() == Some('[') {
+            chars.next();
+            // Parameters until the final byte like "m"
+            for c in chars.by_ref() {
+                if ('\u{40}'..='\u{7E}').contains(&c) {
+                    break;
+                }
+            }
+        } else {
+            chars.next();
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone};
+    use pretty_assertions::assert_eq;
+
+    use crate::core::{
+        config::JunitGroup,
+        output::{junit_xml, relative},
+        types,
+    };
+
+    fn report() -> types::AnalyseReport {
+        let location = |row| types::Location {
+            path: "/tmp/project/src/main.rs".to_string(),
+            row,
+            col: 5,
+        };
+
+        types::AnalyseReport {
+            source: "command: cargo clippy".to_string(),
+            project: "/tmp/project".to_string(),
+            date: Local
+                .with_ymd_and_hms(2024, 5, 1, 10, 0, 0)
+                .single()
+                .expect("valid date"),
+            errors: vec![
+                types::Message {
+                    error: "unused variable: `x`".to_string(),
+                    locations: vec![location(3)],
+                },
+                types::Message {
+                    error: "this `if` has identical blocks".to_string(),
+                    locations: vec![location(8)],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn should_write_testcase_per_file() {
+        assert_eq!(
+            junit_xml(&report(), &JunitGroup::File),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="logana" tests="1" failures="1">
+  <testsuite name="command: cargo clippy" tests="1" failures="1" timestamp="2024-05-01T10:00:00">
+    <testcase name="src/main.rs" classname="logana" file="src/main.rs">
+      <failure message="2 problems" type="logana">src/main.rs:3:5: unused variable: `x`
+src/main.rs:8:5: this `if` has identical blocks</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+
+    #[test]
+    fn should_write_testcase_per_message() {
+        assert_eq!(
+            junit_xml(&report(), &JunitGroup::Message),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="logana" tests="2" failures="2">
+  <testsuite name="command: cargo clippy" tests="2" failures="2" timestamp="2024-05-01T10:00:00">
+    <testcase name="src/main.rs:3:5" classname="logana" file="src/main.rs">
+      <failure message="unused variable: `x`" type="logana">src/main.rs:3:5: unused variable: `x`</failure>
+    </testcase>
+    <testcase name="src/main.rs:8:5" classname="logana" file="src/main.rs">
+      <failure message="this `if` has identical blocks" type="logana">src/main.rs:8:5: this `if` has identical blocks</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+
+    #[test]
+    fn should_only_shorten_paths_of_the_project() {
+        let report = report();
+
+        assert_eq!(relative(&report, "/tmp/project/src/main.rs"), "src/main.rs");
+        assert_eq!(
+            relative(&report, "/tmp/project2/a.rs"),
+            "/tmp/project2/a.rs"
+        );
+    }
+
+    #[test]
+    fn should_fail_for_messages_without_location() {
+        let mut report = report();
+        report.errors = vec![types::Message {
+            error: "linking with `cc` failed\x1B[0m <exit status: 1>".to_string(),
+            locations: vec![],
+        }];
+
+        assert_eq!(
+            junit_xml(&report, &JunitGroup::Message),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="logana" tests="1" failures="1">
+  <testsuite name="command: cargo clippy" tests="1" failures="1" timestamp="2024-05-01T10:00:00">
+    <testcase name="command: cargo clippy" classname="logana">
+      <failure message="linking with `cc` failed &lt;exit status: 1&gt;" type="logana">linking with `cc` failed &lt;exit status: 1&gt;</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
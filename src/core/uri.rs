@@ -0,0 +1,68 @@
+/// Converts a file uri into a path
+///
+/// Gets input like:
+/// "`file:///home/emily/my%20project/src/main.rs`"
+///          -------------------------------------
+///          path with percent encoding removed
+#[must_use]
+pub fn file_uri_to_path(uri: &str) -> Option<String> {
+    let rest = uri.strip_prefix("file://")?;
+    // Skip the host in "file://hostname/path"
+    let path = &rest[rest.find('/')?..];
+    let path = percent_decode(path);
+
+    // Windows paths look like "/C:/project"
+    if path.get(2..3) == Some(":") {
+        return Some(path[1..].to_string());
+    }
+
+    Some(path)
+}
+
+/// Removes percent encoding like "%20" from a string
+#[must_use]
+pub fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(byte) = input
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&out).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::uri::file_uri_to_path;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_convert_file_uri() {
+        assert_eq!(
+            file_uri_to_path("file:///tmp/my%20project/src/A.kt"),
+            Some("/tmp/my project/src/A.kt".to_string())
+        );
+        assert_eq!(
+            file_uri_to_path("file://localhost/tmp/project/main.mjs"),
+            Some("/tmp/project/main.mjs".to_string())
+        );
+        assert_eq!(
+            file_uri_to_path("file:///C:/project/main.mjs"),
+            Some("C:/project/main.mjs".to_string())
+        );
+        assert_eq!(file_uri_to_path("https://example.com"), None);
+    }
+}
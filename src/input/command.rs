@@ -1,8 +1,9 @@
-use regex::Regex;
 use std::io::{BufRead, BufReader};
 
 use subprocess::{Exec, Redirection};
 
+use super::sanitise;
+
 /// Runs the passed command in a shell
 #[must_use]
 pub fn run_command_and_collect(command: &str) -> String {
@@ -23,19 +24,11 @@ pub fn run_command_and_collect(command: &str) -> String {
             let line = format!("{line}\n");
             print!("{line}");
 
-            output.push_str(&strip_color(&line));
+            output.push_str(&line);
         } else {
             println!("{line:?}");
         }
     });
 
-    output
-}
-
-/// Remove shell colors
-#[must_use]
-pub fn strip_color(text: &str) -> String {
-    let re = Regex::new("\\x1B\\[(?:;?[0-9]{1,3})+[mGK]")
-        .expect("Unbale to create regex to strip color");
-    re.replace_all(text, String::new()).to_string()
+    sanitise::sanitise(&output)
 }
@@ -0,0 +1,256 @@
+use std::{
+    fs,
+    io::{self, Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+use flate2::read::MultiGzDecoder;
+use zip::ZipArchive;
+
+/// A log that was loaded for [`crate::config::InputKind::File`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Log {
+    /// The file or archive entry the log comes from
+    pub name: String,
+    /// The text of the log
+    pub content: String,
+}
+
+/// Loads all logs of a target
+///
+/// The target can be a file, a directory or a glob pattern.
+/// ".gz", ".zst" and ".zip" files are decompressed.
+pub fn load(target: &str) -> io::Result<Vec<Log>> {
+    let mut logs = vec![];
+    for path in files(target)? {
+        let name = path.to_string_lossy().to_string();
+        logs.extend(decode(&name, fs::read(&path)?)?);
+    }
+
+    Ok(logs)
+}
+
+/// Checks if a target can contain multiple logs
+///
+/// This is the case for directories, glob patterns and ".zip" files even if only one log is found.
+#[must_use]
+pub fn is_collection(target: &str) -> bool {
+    let path = Path::new(target);
+
+    path.is_dir() || is_glob(target) || strip_extension(target, "zip").is_some()
+}
+
+/// Checks that a target is a plain file that can be read like "tail -f"
+pub fn check_follow(target: &str) -> Result<(), &'static str> {
+    let path = Path::new(target);
+    if path.is_dir() {
+        return Err("The target of --follow can not be a directory");
+    }
+    if is_glob(target) {
+        return Err("The target of --follow can not be a glob pattern");
+    }
+    if ["gz", "zst", "zip"]
+        .iter()
+        .any(|extension| strip_extension(target, extension).is_some())
+    {
+        return Err("The target of --follow can not be a compressed file");
+    }
+
+    Ok(())
+}
+
+fn is_glob(target: &str) -> bool {
+    !Path::new(target).exists() && target.contains(['*', '?', '['])
+}
+
+/// Returns the files of a target
+fn files(target: &str) -> io::Result<Vec<PathBuf>> {
+    let path = Path::new(target);
+    if path.is_dir() {
+        let mut files = vec![];
+        walk(path, &mut files)?;
+        files.sort();
+        return Ok(files);
+    }
+
+    if is_glob(target) {
+        let paths = glob::glob(target)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        if paths.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No file matches {target}"),
+            ));
+        }
+        return Ok(paths);
+    }
+
+    Ok(vec![path.to_path_buf()])
+}
+
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        // The file type does not follow symlinks, so a link to a parent can not loop
+        let file_type = entry.file_type()?;
+        let path = entry.path();
+        if file_type.is_dir() {
+            walk(&path, files)?;
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Decompresses the content of a file based on its extension
+fn decode(name: &str, content: Vec<u8>) -> io::Result<Vec<Log>> {
+    if let Some(inner) = strip_extension(name, "gz") {
+        let mut decoded = vec![];
+        MultiGzDecoder::new(content.as_slice()).read_to_end(&mut decoded)?;
+        return decode(inner, decoded).map(|logs| rename(logs, inner, name));
+    }
+
+    if let Some(inner) = strip_extension(name, "zst") {
+        let decoded = zstd::decode_all(content.as_slice())?;
+        return decode(inner, decoded).map(|logs| rename(logs, inner, name));
+    }
+
+    if strip_extension(name, "zip").is_some() {
+        let mut archive = ZipArchive::new(Cursor::new(content))?;
+        let mut logs = vec![];
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)?;
+            if !entry.is_file() {
+                continue;
+            }
+            let entry_name = format!("{name}:{}", entry.name());
+            let mut decoded = vec![];
+            entry.read_to_end(&mut decoded)?;
+            logs.extend(decode(&entry_name, decoded)?);
+        }
+        return Ok(logs);
+    }
+
+    Ok(vec![Log {
+        name: name.to_string(),
+        content: String::from_utf8_lossy(&content).to_string(),
+    }])
+}
+
+/// Removes an extension like "gz" from a name ignoring the case
+fn strip_extension<'a>(name: &'a str, extension: &str) -> Option<&'a str> {
+    let matches = Path::new(name)
+        .extension()
+        .is_some_and(|found| found.eq_ignore_ascii_case(extension));
+
+    matches.then(|| &name[..name.len() - extension.len() - 1])
+}
+
+/// Names logs after the compressed file instead of the decompressed name
+fn rename(logs: Vec<Log>, inner: &str, name: &str) -> Vec<Log> {
+    logs.into_iter()
+        .map(|log| Log {
+            name: log.name.replacen(inner, name, 1),
+            content: log.content,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+    use pretty_assertions::assert_eq;
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    use crate::input::file::{decode, files, Log};
+
+    static LOG: &str = include_str!("../../tests/cargo_1.log");
+
+    #[test]
+    fn should_decode_gz() {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(LOG.as_bytes()).expect("compress log");
+        let content = encoder.finish().expect("compress log");
+
+        assert_eq!(
+            decode("build.log.gz", content).ok(),
+            Some(vec![Log {
+                name: "build.log.gz".to_string(),
+                content: LOG.to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn should_decode_upper_case_extension() {
+        let content = zstd::encode_all(LOG.as_bytes(), 0).expect("compress log");
+
+        assert_eq!(
+            decode("BUILD.LOG.ZST", content).ok(),
+            Some(vec![Log {
+                name: "BUILD.LOG.ZST".to_string(),
+                content: LOG.to_string(),
+            }])
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_not_follow_directory_symlinks() {
+        let dir = std::env::temp_dir().join(format!("logana_walk_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create test dir");
+        std::fs::write(dir.join("build.log"), LOG).expect("write test log");
+        std::os::unix::fs::symlink(".", dir.join("self")).expect("create symlink");
+
+        let target = dir.to_str().expect("temp dir should be valid utf-8");
+        assert_eq!(files(target).ok(), Some(vec![dir.join("build.log")]));
+
+        std::fs::remove_dir_all(&dir).expect("remove test dir");
+    }
+
+    #[test]
+    fn should_decode_zst() {
+        let content = zstd::encode_all(LOG.as_bytes(), 0).expect("compress log");
+
+        assert_eq!(
+            decode("build.log.zst", content).ok(),
+            Some(vec![Log {
+                name: "build.log.zst".to_string(),
+                content: LOG.to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn should_decode_zip() {
+        let mut writer = ZipWriter::new(std::io::Cursor::new(vec![]));
+        for module in ["app", "core"] {
+            writer
+                .start_file(format!("{module}/build.log"), SimpleFileOptions::default())
+                .expect("add zip entry");
+            writer.write_all(LOG.as_bytes()).expect("write zip entry");
+        }
+        let content = writer.finish().expect("create zip").into_inner();
+
+        assert_eq!(
+            decode("logs.zip", content).ok(),
+            Some(vec![
+                Log {
+                    name: "logs.zip:app/build.log".to_string(),
+                    content: LOG.to_string(),
+                },
+                Log {
+                    name: "logs.zip:core/build.log".to_string(),
+                    content: LOG.to_string(),
+                }
+            ])
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::{fs::Metadata, io::SeekFrom};
+
+use tokio::{
+    fs::{metadata, File},
+    io::{self, AsyncReadExt, AsyncSeekExt},
+};
+
+use crate::core::{config::Args, output};
+
+use super::{handle::analyse, sanitise};
+
+/// Reads the file target like "tail -f" and analyses it again whenever it grows
+///
+/// When the file gets truncated or replaced by log rotation it is read from the start again.
+pub async fn follow(args: &Args, target: &str, project_dir: &str) {
+    let mut followed = Followed::default();
+
+    loop {
+        match followed.read(target).await {
+            Ok(true) => {
+                let content = sanitise::sanitise(&String::from_utf8_lossy(&followed.content));
+                let report = analyse(args, format!("file: {target}"), &content, project_dir);
+                output::produce(args, &report);
+            }
+            Ok(false) => {}
+            // The file is missing while it is being rotated
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => {
+                println!("Got the following error wile following the target: {e:?}");
+                return;
+            }
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    }
+}
+
+/// The state of a followed file
+#[derive(Default)]
+struct Followed {
+    /// Everything that was read since the file was created or truncated
+    content: Vec<u8>,
+    /// Device and inode to detect when the file was replaced
+    identity: Option<(u64, u64)>,
+    /// If the file was read at least once
+    started: bool,
+}
+
+impl Followed {
+    /// Reads the new part of the file and returns if the content changed
+    async fn read(&mut self, target: &str) -> io::Result<bool> {
+        let meta = metadata(target).await?;
+        let identity = Some(identity(&meta));
+        let mut changed = !self.started;
+        self.started = true;
+
+        let replaced = identity != self.identity;
+        let truncated = meta.len() < self.content.len() as u64;
+        if replaced || truncated {
+            changed |= !self.content.is_empty();
+            self.content.clear();
+            self.identity = identity;
+        }
+
+        if meta.len() > self.content.len() as u64 {
+            let mut file = File::open(target).await?;
+            file.seek(SeekFrom::Start(self.content.len() as u64))
+                .await?;
+            let read = file.read_to_end(&mut self.content).await?;
+            changed |= read > 0;
+        }
+
+        Ok(changed)
+    }
+}
+
+/// The creation time is not supported by many linux file systems, so the inode is used
+#[cfg(unix)]
+fn identity(meta: &Metadata) -> (u64, u64) {
+    use std::os::unix::fs::MetadataExt;
+
+    (meta.dev(), meta.ino())
+}
+
+/// Without inodes only truncation is detected
+#[cfg(not(unix))]
+const fn identity(_meta: &Metadata) -> (u64, u64) {
+    (0, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use std::path::PathBuf;
+
+    use crate::input::follow::Followed;
+
+    /// A directory that is not shared with other tests or processes
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("logana_{name}_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create test dir");
+        dir
+    }
+
+    #[tokio::test]
+    async fn should_follow_growing_and_truncated_file() {
+        let dir = test_dir("follow_truncated");
+        let path = dir.join("build.log");
+        let target = path.to_str().expect("temp dir should be valid utf-8");
+        std::fs::write(&path, "first\n").expect("write test log");
+
+        let mut followed = Followed::default();
+        assert_eq!(followed.read(target).await.ok(), Some(true));
+        assert_eq!(followed.content, b"first\n");
+        assert_eq!(followed.read(target).await.ok(), Some(false));
+
+        std::fs::write(&path, "first\nsecond\n").expect("write test log");
+        assert_eq!(followed.read(target).await.ok(), Some(true));
+        assert_eq!(followed.content, b"first\nsecond\n");
+
+        std::fs::write(&path, "new\n").expect("write test log");
+        assert_eq!(followed.read(target).await.ok(), Some(true));
+        assert_eq!(followed.content, b"new\n");
+
+        std::fs::remove_dir_all(&dir).expect("remove test dir");
+    }
+
+    #[tokio::test]
+    async fn should_follow_rotated_file() {
+        let dir = test_dir("follow_rotated");
+        let path = dir.join("build.log");
+        let target = path.to_str().expect("temp dir should be valid utf-8");
+        std::fs::write(&path, "old build\n").expect("write test log");
+
+        let mut followed = Followed::default();
+        assert_eq!(followed.read(target).await.ok(), Some(true));
+
+        std::fs::rename(&path, dir.join("build.log.1")).expect("rotate test log");
+        std::fs::write(&path, "new build\n").expect("write test log");
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .expect("open test log");
+        std::io::Write::write_all(&mut file, b"error\n").expect("append test log");
+
+        assert_eq!(followed.read(target).await.ok(), Some(true));
+        assert_eq!(followed.content, b"new build\nerror\n");
+
+        std::fs::remove_dir_all(&dir).expect("remove test dir");
+    }
+}
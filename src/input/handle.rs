@@ -3,21 +3,44 @@ use tokio::io::AsyncReadExt;
 
 use chrono::Local;
 use notify::{PollWatcher, RecursiveMode, Watcher};
-use tokio::{fs::read_to_string, io};
+use regex::Regex;
+use tokio::io;
 
 use crate::{
     analyser,
     core::{
-        config::{Args, InputKind, ParserKind},
+        config::{Args, BuildSelection, FollowMode, InputKind, ParserKind, SourceMaps},
         output, types,
     },
 };
 
-use super::{command, split, tmux, wezterm};
+#[cfg(unix)]
+use super::socket;
+use super::{command, file, follow, sanitise, split, tmux, wezterm};
 
 /// Will handle the userinput and call the analyser
-/// This will also handle the wach flag
+/// This will also handle the wach and follow flag
 pub async fn handle(args: &Args, project_dir: &str) {
+    if args.follow == FollowMode::Tail {
+        if args.input != Some(InputKind::File) {
+            println!("--follow only works with the input file");
+            return;
+        }
+        if let Some(target) = &args.target {
+            if let Err(e) = file::check_follow(target) {
+                println!("{e}");
+                return;
+            }
+            follow::follow(args, target, project_dir).await;
+            return;
+        }
+    }
+
+    if args.input == Some(InputKind::Socket) {
+        handle_socket(args, project_dir).await;
+        return;
+    }
+
     if let Some(report) = handle_input(args, project_dir).await {
         output::produce(args, &report);
     }
@@ -27,6 +50,38 @@ pub async fn handle(args: &Args, project_dir: &str) {
     }
 }
 
+/// Analyses every build that is pushed to the socket
+#[cfg(unix)]
+async fn handle_socket(args: &Args, project_dir: &str) {
+    let Some(target) = &args.target else {
+        println!("The required argument target is missing");
+        return;
+    };
+    let source = match socket::Source::open(target).await {
+        Ok(source) => source,
+        Err(e) => {
+            println!("Unable to listen on the target: {e:?}");
+            return;
+        }
+    };
+
+    loop {
+        match source.receive().await {
+            Ok(content) => {
+                let content = sanitise::sanitise(&content);
+                let report = analyse(args, format!("socket: {target}"), &content, project_dir);
+                output::produce(args, &report);
+            }
+            Err(e) => println!("Got the following error wile reading the target: {e:?}"),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn handle_socket(_args: &Args, _project_dir: &str) {
+    println!("The input socket is only supported on unix");
+}
+
 async fn handle_watch(args: &Args, project_dir: &str) {
     if let Some(watch) = args.watch.clone() {
         let (otx, orx) = tokio::sync::watch::channel("watch");
@@ -97,6 +152,7 @@ pub async fn handle_input(args: &Args, project_dir: &str) -> Option<types::Analy
                 .read_to_string(&mut buffer)
                 .await
                 .unwrap_or_default();
+            let buffer = sanitise::sanitise(&buffer);
             let report = analyse(args, "stdin".to_string(), &buffer, project_dir);
 
             return Some(report);
@@ -124,27 +180,47 @@ pub async fn handle_input(args: &Args, project_dir: &str) -> Option<types::Analy
                     println!("The required argument splitby s missing");
                     return None;
                 };
-                if let Some(report) =
-                    split::builds(command::strip_color(content.as_str()).as_str(), splitby)
-                        .iter()
-                        .map(|build| analyse(args, format!("pane: {target}"), build, project_dir))
-                        .rfind(|analyse| !analyse.errors.is_empty())
-                {
-                    return Some(report);
+                return select_build(args, target, &content, splitby, project_dir);
+            }
+        }
+        // handle() serves every build with handle_socket, here only the next one is analysed
+        #[cfg(unix)]
+        Some(InputKind::Socket) => {
+            let Some(target) = &args.target else {
+                println!("The required argument target is missing");
+                return None;
+            };
+            let content = match socket::Source::open(target).await {
+                Ok(source) => source.receive().await,
+                Err(e) => Err(e),
+            };
+            match content {
+                Ok(content) => {
+                    let content = sanitise::sanitise(&content);
+                    return Some(analyse(
+                        args,
+                        format!("socket: {target}"),
+                        &content,
+                        project_dir,
+                    ));
+                }
+                Err(e) => {
+                    println!("Got the following error wile reading the target: {e:?}");
                 }
             }
         }
+        #[cfg(not(unix))]
+        Some(InputKind::Socket) => {
+            println!("The input socket is only supported on unix");
+        }
         Some(InputKind::File) => {
             let Some(target) = &args.target else {
                 println!("The required argument target is missing");
                 return None;
             };
             let target = target.as_str();
-            match read_to_string(target).await {
-                Ok(content) => {
-                    let report = analyse(args, format!("file: {target}"), &content, project_dir);
-                    return Some(report);
-                }
+            match file::load(target) {
+                Ok(logs) => return Some(analyse_logs(args, target, logs, project_dir)),
                 Err(e) => {
                     println!("Got the following error wile readindg the target: {e:?}");
                 }
@@ -158,6 +234,68 @@ pub async fn handle_input(args: &Args, project_dir: &str) -> Option<types::Analy
     None
 }
 
+/// Analyses the build of a pane that was selected by the arguments
+fn select_build(
+    args: &Args,
+    target: &str,
+    content: &str,
+    splitby: &str,
+    project_dir: &str,
+) -> Option<types::AnalyseReport> {
+    let mut builds = split::builds(&sanitise::sanitise(content), splitby);
+    if let Some(filter) = &args.build_filter {
+        match Regex::new(filter) {
+            Ok(filter) => builds = split::filter_command(builds, &filter),
+            Err(e) => {
+                println!("The argument build-filter is not a valid regex: {e}");
+                return None;
+            }
+        }
+    }
+    let mut reports = builds
+        .iter()
+        .filter(|build| split::has_output(build))
+        .map(|build| analyse(args, format!("pane: {target}"), build, project_dir));
+    match (args.nth, &args.build) {
+        // clap rejects 0, "checked_sub" only guards library callers
+        (Some(nth), _) => reports.nth_back(nth.checked_sub(1)?),
+        (None, BuildSelection::Latest) => reports.next_back(),
+        (None, BuildSelection::LastFailed) => reports.rfind(|analyse| !analyse.errors.is_empty()),
+    }
+}
+
+/// Analyses all logs of a file target into one report
+///
+/// When the target can contain multiple logs every error starts with the name of its log
+/// like "`[logs/app.log] error: ...`". This is also done when only one log was found.
+fn analyse_logs(
+    args: &Args,
+    target: &str,
+    logs: Vec<file::Log>,
+    project_dir: &str,
+) -> types::AnalyseReport {
+    let many = file::is_collection(target);
+    let mut errors = vec![];
+    for log in logs {
+        let content = sanitise::sanitise(&log.content);
+        let log_errors = analyse(args, log.name.clone(), &content, project_dir).errors;
+        errors.extend(log_errors.into_iter().map(|mut message| {
+            // Show which log the error is from
+            if many {
+                message.error = format!("[{}] {}", log.name, message.error);
+            }
+            message
+        }));
+    }
+
+    types::AnalyseReport {
+        project: project_dir.to_string(),
+        date: Local::now(),
+        source: format!("file: {target}"),
+        errors,
+    }
+}
+
 /// Analyse the input string and returning a `AnalyseReport` for all parsers
 ///
 /// # Arguments
@@ -176,8 +314,12 @@ pub fn analyse(
         Some(ParserKind::Biome) => analyser::biome::analyse(input, project_dir),
         Some(ParserKind::Cargo) => analyser::cargo::analyse(input, project_dir),
         Some(ParserKind::Clang) => analyser::clang::analyse(input, project_dir),
+        Some(ParserKind::Dotnet) => analyser::dotnet::analyse(input, project_dir),
         Some(ParserKind::Dune) => analyser::dune::analyse(input, project_dir),
+        Some(ParserKind::Elixir) => analyser::elixir::analyse(input, project_dir),
+        Some(ParserKind::Esbuild) => analyser::esbuild::analyse(input, project_dir),
         Some(ParserKind::Eslint) => analyser::eslint::analyse(input, project_dir),
+        Some(ParserKind::Ghc) => analyser::ghc::analyse(input, project_dir),
         Some(ParserKind::Go) => analyser::go::analyse(input, project_dir),
         Some(ParserKind::Gradle) => analyser::gradle::analyse(input, project_dir),
         Some(ParserKind::Gcc) => analyser::gcc::analyse(input, project_dir),
@@ -191,11 +333,32 @@ pub fn analyse(
                 analyser::java::analyse(input, project_dir, package)
             },
         ),
+        Some(ParserKind::Jest) => analyser::jest::analyse(input, project_dir),
+        Some(ParserKind::Junit) => analyser::junit::analyse(input, project_dir),
         Some(ParserKind::KarmaJasmine) => analyser::karma_jasmine::analyse(input, project_dir),
+        Some(ParserKind::Kotlin) => analyser::kotlin::analyse(input, project_dir),
+        Some(ParserKind::Linker) => analyser::linker::analyse(input, project_dir),
         Some(ParserKind::Maven) => analyser::maven::analyse(input, project_dir),
+        Some(ParserKind::Mypy) => analyser::mypy::analyse(input, project_dir),
         Some(ParserKind::Nix) => analyser::nix::analyse(input, project_dir),
+        Some(ParserKind::Node) => {
+            analyser::node::analyse(input, project_dir, args.source_maps == SourceMaps::Map)
+        }
         Some(ParserKind::Odin) => analyser::odin::analyse(input, project_dir),
+        Some(ParserKind::Phpstan) => analyser::phpstan::analyse(input, project_dir),
+        Some(ParserKind::Phpunit) => analyser::phpunit::analyse(input, project_dir),
+        Some(ParserKind::Pyright) => analyser::pyright::analyse(input, project_dir),
+        Some(ParserKind::Pytest) => analyser::pytest::analyse(input, project_dir),
+        Some(ParserKind::Python) => analyser::python::analyse(input, project_dir),
+        Some(ParserKind::Rspec) => analyser::rspec::analyse(input, project_dir),
+        Some(ParserKind::Rubocop) => analyser::rubocop::analyse(input, project_dir),
+        Some(ParserKind::Ruff) => analyser::ruff::analyse(input, project_dir),
+        Some(ParserKind::Scala) => analyser::scala::analyse(input, project_dir),
+        Some(ParserKind::Swift) => analyser::swift::analyse(input, project_dir),
+        Some(ParserKind::Tsc) => analyser::tsc::analyse(input, project_dir),
         Some(ParserKind::V) => analyser::v::analyse(input, project_dir),
+        Some(ParserKind::Vitest) => analyser::vitest::analyse(input, project_dir),
+        Some(ParserKind::Webpack) => analyser::webpack::analyse(input, project_dir),
         Some(ParserKind::Zig) => analyser::zig::analyse(input, project_dir),
         None => {
             println!("There was no --parser defined and it could not be guessed");
@@ -211,3 +374,85 @@ pub fn analyse(
         errors,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        core::config::{Args, BuildSelection, ParserKind},
+        input::handle::select_build,
+    };
+
+    static LOG: &str = include_str!("../../tests/split_builds.log");
+
+    /// Selects a build and returns the files of its errors
+    fn select(args: &Args) -> Option<Vec<String>> {
+        let report = select_build(args, "%1", LOG, "$ ", "/tmp/project")?;
+
+        Some(
+            report
+                .errors
+                .into_iter()
+                .flat_map(|message| message.locations)
+                .map(|location| location.path)
+                .collect(),
+        )
+    }
+
+    fn args() -> Args {
+        Args {
+            parser: Some(ParserKind::Cargo),
+            ..Args::default()
+        }
+    }
+
+    #[test]
+    fn should_select_last_failed_build() {
+        assert_eq!(
+            select(&args()),
+            Some(vec!["/tmp/project/src/lib.rs".to_string()])
+        );
+    }
+
+    #[test]
+    fn should_select_latest_build() {
+        let args = Args {
+            build: BuildSelection::Latest,
+            ..args()
+        };
+
+        assert_eq!(select(&args), Some(vec![]));
+    }
+
+    #[test]
+    fn should_select_nth_build() {
+        let nth = |nth| Args {
+            nth: Some(nth),
+            ..args()
+        };
+
+        assert_eq!(select(&nth(1)), Some(vec![]));
+        assert_eq!(
+            select(&nth(4)),
+            Some(vec!["/tmp/project/src/main.rs".to_string()])
+        );
+        assert_eq!(select(&nth(5)), None);
+        assert_eq!(select(&nth(0)), None);
+        assert!(Args::try_parse_from(["logana", "--nth", "0"]).is_err());
+    }
+
+    #[test]
+    fn should_select_filtered_build() {
+        let args = Args {
+            build_filter: Some("cargo (build|test)".to_string()),
+            ..args()
+        };
+
+        assert_eq!(
+            select(&args),
+            Some(vec!["/tmp/project/src/main.rs".to_string()])
+        );
+    }
+}
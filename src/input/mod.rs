@@ -1,7 +1,16 @@
 /// Loads the log for [`crate::config::InputKind::Command`]
 pub mod command;
+/// Loads and decompresses the logs for [`crate::config::InputKind::File`]
+pub mod file;
+/// Follows a growing file for [`crate::config::InputKind::File`]
+pub mod follow;
 /// Decides with [`crate::config::InputKind`] to choose and executes the analyser
 pub mod handle;
+/// Renders terminal control sequences of a collected log
+pub mod sanitise;
+/// Loads the log for [`crate::config::InputKind::Socket`]
+#[cfg(unix)]
+pub mod socket;
 /// A util function for splitting builds
 pub mod split;
 /// Loads the log for [`crate::config::InputKind::Tmux`]
@@ -0,0 +1,282 @@
+use std::{iter::Peekable, str::Chars};
+
+use crate::core::uri;
+
+/// Renders a captured log the way a terminal would display it
+///
+/// Carriage returns, backspaces, cursor movement and erase sequences overwrite
+/// the text like they would in a terminal. All other escape sequences are removed.
+/// The target of an OSC 8 hyperlink is kept behind the link text when it points
+/// to a file that is not visible in the text.
+#[must_use]
+pub fn sanitise(text: &str) -> String {
+    let mut screen = Screen::default();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1B' => match chars.next() {
+                Some('[') => {
+                    let (params, action) = parse_csi(&mut chars);
+                    screen.csi(&params, action);
+                }
+                Some(']') => screen.osc(&parse_osc(&mut chars)),
+                // Character set selection like "\x1B(B"
+                Some('(' | ')' | '*' | '+') => {
+                    chars.next();
+                }
+                _ => {}
+            },
+            '\n' => screen.new_line(),
+            '\r' => screen.col = 0,
+            '\x08' => screen.col = screen.col.saturating_sub(1),
+            '\t' => screen.write(c),
+            c if c.is_control() => {}
+            c => screen.write(c),
+        }
+    }
+
+    screen.render()
+}
+
+/// Parses a control sequence after "\x1B["
+///
+/// Gets input like:
+/// "1;31m"
+///  ---- -
+///  |    action
+///  params
+fn parse_csi(chars: &mut Peekable<Chars>) -> (String, char) {
+    let mut params = String::new();
+    for c in chars.by_ref() {
+        if ('\x40'..='\x7E').contains(&c) {
+            return (params, c);
+        }
+        params.push(c);
+    }
+
+    (params, '\0')
+}
+
+/// Parses an operating system command after "\x1B]"
+///
+/// It ends with BEL or with the string terminator "\x1B\\"
+fn parse_osc(chars: &mut Peekable<Chars>) -> String {
+    let mut command = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x07' => break,
+            '\x1B' => {
+                if chars.peek() == Some(&'\\') {
+                    chars.next();
+                }
+                break;
+            }
+            c => command.push(c),
+        }
+    }
+
+    command
+}
+
+/// Furthest the cursor can be moved past the written text
+///
+/// A sequence like "\x1B[999999999C" would otherwise pad the line with a gigabyte of spaces
+const MAX_MOVE: usize = 1024;
+
+/// Number parameter of a control sequence with a default of 1
+fn count(params: &str) -> usize {
+    params.parse().unwrap_or(1).clamp(1, MAX_MOVE)
+}
+
+#[derive(Default)]
+struct Screen {
+    lines: Vec<Vec<char>>,
+    row: usize,
+    col: usize,
+    /// First row of the screen that absolute positions are relative to
+    top: usize,
+    /// Target of the open hyperlink and the text that was written since
+    link: Option<(String, String)>,
+}
+
+impl Screen {
+    fn line(&mut self) -> &mut Vec<char> {
+        while self.lines.len() <= self.row {
+            self.lines.push(vec![]);
+        }
+        &mut self.lines[self.row]
+    }
+
+    fn write(&mut self, c: char) {
+        let col = self.col;
+        let line = self.line();
+        while line.len() < col {
+            line.push(' ');
+        }
+        if col < line.len() {
+            line[col] = c;
+        } else {
+            line.push(c);
+        }
+        self.col += 1;
+
+        if let Some((_, text)) = &mut self.link {
+            text.push(c);
+        }
+    }
+
+    fn move_to(&mut self, row: usize, col: usize) {
+        self.row = row.min(self.lines.len() + MAX_MOVE);
+        self.col = col.min(self.line().len() + MAX_MOVE);
+    }
+
+    fn write_str(&mut self, text: &str) {
+        text.chars().for_each(|c| self.write(c));
+    }
+
+    fn new_line(&mut self) {
+        self.line();
+        self.row += 1;
+        self.col = 0;
+        self.line();
+    }
+
+    fn csi(&mut self, params: &str, action: char) {
+        // Private modes like "\x1B[?25l" do not change the text
+        if params.starts_with('?') {
+            return;
+        }
+        let (row, col) = (self.row, self.col);
+        match action {
+            'A' => self.move_to(row.saturating_sub(count(params)), col),
+            'B' => self.move_to(row + count(params), col),
+            'C' => self.move_to(row, col + count(params)),
+            'D' => self.move_to(row, col.saturating_sub(count(params))),
+            'E' => self.move_to(row + count(params), 0),
+            'F' => self.move_to(row.saturating_sub(count(params)), 0),
+            'G' => self.move_to(row, count(params) - 1),
+            // Like "\x1B[2;5H", rows are counted from the last cleared screen
+            'H' | 'f' => {
+                let (top, left) = params.split_once(';').unwrap_or((params, ""));
+                self.move_to(self.top + count(top) - 1, count(left) - 1);
+            }
+            'K' => {
+                let line = self.line();
+                match params {
+                    "1" => line.iter_mut().take(col + 1).for_each(|c| *c = ' '),
+                    "2" => line.clear(),
+                    _ => line.truncate(col),
+                }
+            }
+            'J' => {
+                // Clearing the whole screen would also remove the log, only clear below the cursor
+                if params.is_empty() || params == "0" {
+                    self.line().truncate(col);
+                    self.lines.truncate(self.row + 1);
+                } else if params == "2" {
+                    self.top = self.lines.len();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles hyperlinks like "8;;file:///tmp/project/src/main.rs"
+    fn osc(&mut self, command: &str) {
+        let mut parts = command.splitn(3, ';');
+        if parts.next() != Some("8") {
+            return;
+        }
+        let target = parts.nth(1).unwrap_or_default();
+
+        if let Some((target, text)) = self.link.take() {
+            if let Some(path) = uri::file_uri_to_path(&target) {
+                if !text.contains(&path) {
+                    self.write_str(&format!(" ({path})"));
+                }
+            }
+        }
+
+        if !target.is_empty() {
+            self.link = Some((target.to_string(), String::new()));
+        }
+    }
+
+    fn render(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::sanitise::sanitise;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_strip_colors() {
+        assert_eq!(
+            sanitise("\x1B[1m\x1B[31merror[E0425]\x1B[0m\x1B[1m: cannot find value\x1B[0m\n"),
+            "error[E0425]: cannot find value\n"
+        );
+    }
+
+    #[test]
+    fn should_render_carriage_return() {
+        assert_eq!(
+            sanitise("Downloading 10%\rDownloading 100%\nBUILD FAILED\n"),
+            "Downloading 100%\nBUILD FAILED\n"
+        );
+        assert_eq!(
+            sanitise("[=====>    ] 50%\r\x1B[K> Task :app:compileJava\n"),
+            "> Task :app:compileJava\n"
+        );
+    }
+
+    #[test]
+    fn should_render_cursor_up() {
+        assert_eq!(
+            sanitise("> Task :app:test\n<==> 10% EXECUTING\n\x1B[1A\x1B[2K\x1B[1G/tmp/project/A.java:8: error: ';' expected\n"),
+            "> Task :app:test\n/tmp/project/A.java:8: error: ';' expected\n"
+        );
+    }
+
+    #[test]
+    fn should_render_cursor_position() {
+        assert_eq!(sanitise("first\nsecond\x1B[1;3HX\n"), "fiXst\nsecond");
+        assert_eq!(
+            sanitise("old\n\x1B[2J\x1B[H 50%\x1B[H100%\n"),
+            "old\n\n100%\n"
+        );
+    }
+
+    #[test]
+    fn should_bound_cursor_movement() {
+        assert_eq!(sanitise("a\x1B[999999999Cb").len(), 1026);
+        assert_eq!(
+            sanitise(&format!("a{}b", "\x1B[1000C".repeat(100))).len(),
+            1026
+        );
+        assert!(sanitise("\x1B[999999999B\x1B[999999999;999999999Hb").len() < 4096);
+    }
+
+    #[test]
+    fn should_keep_hyperlink_target() {
+        assert_eq!(
+            sanitise("\x1B]8;;file:///tmp/project/src/main.rs\x1B\\main.rs\x1B]8;;\x1B\\ failed\n"),
+            "main.rs (/tmp/project/src/main.rs) failed\n"
+        );
+        assert_eq!(
+            sanitise("\x1B]8;;file:///tmp/project/src/main.rs\x07/tmp/project/src/main.rs\x1B]8;;\x07:2:5\n"),
+            "/tmp/project/src/main.rs:2:5\n"
+        );
+        assert_eq!(
+            sanitise("see \x1B]8;;https://example.com\x07docs\x1B]8;;\x07\n"),
+            "see docs\n"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::{io::ErrorKind, os::unix::fs::FileTypeExt, path::Path};
+
+use tokio::{
+    fs::{self, File},
+    io::{self, AsyncReadExt},
+    net::UnixListener,
+};
+
+/// Where builds are pushed to
+///
+/// Every connection to a socket and every time a writer closes a fifo is one build.
+pub enum Source {
+    /// A unix domain socket that logana listens on
+    Socket(UnixListener),
+    /// A named pipe that was created with "mkfifo"
+    Fifo(String),
+}
+
+impl Source {
+    /// Uses the target when it is a fifo otherwise a socket is created at the target
+    pub async fn open(target: &str) -> io::Result<Self> {
+        // A symlink is not followed so only a real socket is removed
+        match fs::symlink_metadata(target).await {
+            Ok(meta) if meta.file_type().is_fifo() => return Ok(Self::Fifo(target.to_string())),
+            // Left over from a previous run
+            Ok(meta) if meta.file_type().is_socket() => fs::remove_file(target).await?,
+            Ok(_) => {
+                return Err(io::Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{target} is neither a socket nor a fifo"),
+                ))
+            }
+            Err(_) => {}
+        }
+
+        Ok(Self::Socket(UnixListener::bind(Path::new(target))?))
+    }
+
+    /// Waits for the next build and returns its log
+    pub async fn receive(&self) -> io::Result<String> {
+        let mut content = vec![];
+        match self {
+            Self::Socket(listener) => {
+                let (mut stream, _) = listener.accept().await?;
+                stream.read_to_end(&mut content).await?;
+            }
+            Self::Fifo(path) => {
+                File::open(path).await?.read_to_end(&mut content).await?;
+            }
+        }
+
+        Ok(String::from_utf8_lossy(&content).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use tokio::{io::AsyncWriteExt, net::UnixStream};
+
+    use crate::input::socket::Source;
+
+    #[tokio::test]
+    async fn should_receive_build_per_connection() {
+        let path = std::env::temp_dir().join(format!("logana_socket_{}.sock", std::process::id()));
+        let target = path.to_str().expect("temp dir should be valid utf-8");
+        let source = Source::open(target).await.expect("bind socket");
+
+        let send = async {
+            for build in ["first build\n", "second build\n"] {
+                let mut stream = UnixStream::connect(&path).await.expect("connect");
+                stream.write_all(build.as_bytes()).await.expect("write");
+            }
+        };
+        let receive = async {
+            let first = source.receive().await.ok();
+            let second = source.receive().await.ok();
+            (first, second)
+        };
+        let ((), received) = tokio::join!(send, receive);
+
+        assert_eq!(
+            received,
+            (
+                Some("first build\n".to_string()),
+                Some("second build\n".to_string())
+            )
+        );
+        std::fs::remove_file(&path).expect("remove socket");
+    }
+
+    #[tokio::test]
+    async fn should_receive_build_per_writer_of_fifo() {
+        let path = std::env::temp_dir().join(format!("logana_fifo_{}", std::process::id()));
+        let status = std::process::Command::new("mkfifo")
+            .arg(&path)
+            .status()
+            .expect("run mkfifo");
+        assert!(status.success());
+        let target = path.to_str().expect("temp dir should be valid utf-8");
+        let source = Source::open(target).await.expect("open fifo");
+
+        let send = async {
+            let mut fifo = tokio::fs::OpenOptions::new()
+                .write(true)
+                .open(&path)
+                .await
+                .expect("open fifo for writing");
+            fifo.write_all(b"fifo build\n").await.expect("write");
+        };
+        let ((), received) = tokio::join!(send, source.receive());
+
+        assert_eq!(received.ok(), Some("fifo build\n".to_string()));
+        std::fs::remove_file(&path).expect("remove fifo");
+    }
+
+    #[tokio::test]
+    async fn should_keep_other_files() {
+        let path = std::env::temp_dir().join(format!("logana_not_socket_{}", std::process::id()));
+        std::fs::write(&path, "build.log").expect("write file");
+        let target = path.to_str().expect("temp dir should be valid utf-8");
+
+        assert!(Source::open(target).await.is_err());
+        assert!(path.exists());
+        std::fs::remove_file(&path).expect("remove file");
+    }
+}
@@ -1,3 +1,5 @@
+use regex::Regex;
+
 /// A utility function to split multipel builds
 #[must_use]
 pub fn builds(log: &str, split_symbol: &str) -> Vec<String> {
@@ -32,6 +34,29 @@ pub fn builds(log: &str, split_symbol: &str) -> Vec<String> {
     out
 }
 
+/// Keeps only the builds whose command line matches the filter
+#[must_use]
+pub fn filter_command(builds: Vec<String>, filter: &Regex) -> Vec<String> {
+    builds
+        .into_iter()
+        .filter(|build| {
+            build
+                .lines()
+                .next()
+                .is_some_and(|line| filter.is_match(line))
+        })
+        .collect()
+}
+
+/// Checks if a build printed something after its command line
+///
+/// The prompt that is waiting for the next command is also split as a build.
+/// It should not count as the latest build.
+#[must_use]
+pub fn has_output(build: &str) -> bool {
+    build.lines().skip(1).any(|line| !line.trim().is_empty())
+}
+
 fn combine_lines(start: usize, end: usize, lines: &[&str]) -> String {
     let mut out = String::new();
     for n in start..end {
@@ -46,6 +71,33 @@ fn combine_lines(start: usize, end: usize, lines: &[&str]) -> String {
 
 #[cfg(test)]
 mod tests {
+    use pretty_assertions::assert_eq;
+    use regex::Regex;
+
+    use crate::input::split::{filter_command, has_output};
+
+    #[test]
+    fn should_filter_builds_by_command() {
+        let builds = vec![
+            "emily@dione ~/p> cargo build\n    Finished dev\n".to_string(),
+            "emily@dione ~/p> cargo test\nerror[E0425]: cannot find value\n".to_string(),
+            "emily@dione ~/p> nvim src/main.rs\n".to_string(),
+        ];
+        let filter = Regex::new("cargo t(est)?").expect("valid regex");
+
+        assert_eq!(
+            filter_command(builds, &filter),
+            vec!["emily@dione ~/p> cargo test\nerror[E0425]: cannot find value\n".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_detect_build_output() {
+        assert!(has_output(
+            "emily@dione ~/p> cargo build\n    Finished dev\n"
+        ));
+        assert!(!has_output("emily@dione ~/p [101]> \n\n\n"));
+    }
 
     #[cfg(target = "linux")]
     #[test]
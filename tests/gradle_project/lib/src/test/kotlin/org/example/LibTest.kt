@@ -0,0 +1,11 @@
+package org.example
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class LibTest {
+    @Test
+    fun sums() {
+        assertEquals(4, 1 + 2)
+    }
+}
@@ -0,0 +1,14 @@
+package org.example
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class CoreTest {
+    @Test fun adds() {
+        assertEquals(4, Core().add(2, 1))
+    }
+
+    @Test fun subtracts() {
+        assertEquals(0, Core().sub(2, 1))
+    }
+}